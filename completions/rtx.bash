@@ -99,12 +99,18 @@ _rtx() {
             rtx,mangen)
                 cmd="rtx__mangen"
                 ;;
+            rtx,outdated)
+                cmd="rtx__outdated"
+                ;;
             rtx,p)
                 cmd="rtx__plugins"
                 ;;
             rtx,plugins)
                 cmd="rtx__plugins"
                 ;;
+            rtx,prune)
+                cmd="rtx__prune"
+                ;;
             rtx,render-help)
                 cmd="rtx__render__help"
                 ;;
@@ -123,6 +129,9 @@ _rtx() {
             rtx,uninstall)
                 cmd="rtx__uninstall"
                 ;;
+            rtx,upgrade)
+                cmd="rtx__upgrade"
+                ;;
             rtx,version)
                 cmd="rtx__version"
                 ;;
@@ -291,9 +300,15 @@ _rtx() {
             rtx__help,mangen)
                 cmd="rtx__help__mangen"
                 ;;
+            rtx__help,outdated)
+                cmd="rtx__help__outdated"
+                ;;
             rtx__help,plugins)
                 cmd="rtx__help__plugins"
                 ;;
+            rtx__help,prune)
+                cmd="rtx__help__prune"
+                ;;
             rtx__help,render-help)
                 cmd="rtx__help__render__help"
                 ;;
@@ -312,6 +327,9 @@ _rtx() {
             rtx__help,uninstall)
                 cmd="rtx__help__uninstall"
                 ;;
+            rtx__help,upgrade)
+                cmd="rtx__help__upgrade"
+                ;;
             rtx__help,version)
                 cmd="rtx__help__version"
                 ;;
@@ -345,6 +363,12 @@ _rtx() {
             rtx__help__direnv,exec)
                 cmd="rtx__help__direnv__exec"
                 ;;
+            rtx__help__plugins,history)
+                cmd="rtx__help__plugins__history"
+                ;;
+            rtx__help__plugins,info)
+                cmd="rtx__help__plugins__info"
+                ;;
             rtx__help__plugins,install)
                 cmd="rtx__help__plugins__install"
                 ;;
@@ -354,6 +378,9 @@ _rtx() {
             rtx__help__plugins,ls-remote)
                 cmd="rtx__help__plugins__ls__remote"
                 ;;
+            rtx__help__plugins,rollback)
+                cmd="rtx__help__plugins__rollback"
+                ;;
             rtx__help__plugins,uninstall)
                 cmd="rtx__help__plugins__uninstall"
                 ;;
@@ -378,9 +405,15 @@ _rtx() {
             rtx__plugins,help)
                 cmd="rtx__plugins__help"
                 ;;
+            rtx__plugins,history)
+                cmd="rtx__plugins__history"
+                ;;
             rtx__plugins,i)
                 cmd="rtx__plugins__install"
                 ;;
+            rtx__plugins,info)
+                cmd="rtx__plugins__info"
+                ;;
             rtx__plugins,install)
                 cmd="rtx__plugins__install"
                 ;;
@@ -396,6 +429,9 @@ _rtx() {
             rtx__plugins,ls-remote)
                 cmd="rtx__plugins__ls__remote"
                 ;;
+            rtx__plugins,rollback)
+                cmd="rtx__plugins__rollback"
+                ;;
             rtx__plugins,uninstall)
                 cmd="rtx__plugins__uninstall"
                 ;;
@@ -405,6 +441,12 @@ _rtx() {
             rtx__plugins__help,help)
                 cmd="rtx__plugins__help__help"
                 ;;
+            rtx__plugins__help,history)
+                cmd="rtx__plugins__help__history"
+                ;;
+            rtx__plugins__help,info)
+                cmd="rtx__plugins__help__info"
+                ;;
             rtx__plugins__help,install)
                 cmd="rtx__plugins__help__install"
                 ;;
@@ -414,6 +456,9 @@ _rtx() {
             rtx__plugins__help,ls-remote)
                 cmd="rtx__plugins__help__ls__remote"
                 ;;
+            rtx__plugins__help,rollback)
+                cmd="rtx__plugins__help__rollback"
+                ;;
             rtx__plugins__help,uninstall)
                 cmd="rtx__plugins__help__uninstall"
                 ;;
@@ -478,7 +523,7 @@ _rtx() {
 
     case "${cmd}" in
         rtx)
-            opts="-j -v -h -V --log-level --jobs --verbose --help --version activate alias asdf bin-paths cache complete current deactivate direnv doctor env exec global hook-env implode install latest local ls ls-remote mangen plugins reshim self-update settings shell uninstall version where which render-help help"
+            opts="-j -v -h -V --log-level --jobs --prefer-installed --verbose --help --version activate alias asdf bin-paths cache complete current deactivate direnv doctor env exec global hook-env implode install latest local ls ls-remote outdated mangen plugins prune reshim self-update settings shell uninstall upgrade version where which render-help help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 1 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -504,7 +549,7 @@ _rtx() {
             return 0
             ;;
         rtx__activate)
-            opts="-s -q -j -v -h --shell --status --quiet --log-level --jobs --verbose --help bash fish xonsh zsh"
+            opts="-s -q -j -v -h --shell --status --quiet --log-level --jobs --prefer-installed --verbose --help bash fish xonsh zsh"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -538,7 +583,7 @@ _rtx() {
             return 0
             ;;
         rtx__alias)
-            opts="-p -j -v -h --plugin --log-level --jobs --verbose --help get ls set unset help"
+            opts="-p -j -v -h --plugin --log-level --jobs --prefer-installed --verbose --help get ls set unset help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -572,7 +617,7 @@ _rtx() {
             return 0
             ;;
         rtx__alias__get)
-            opts="-j -v -h --log-level --jobs --verbose --help <PLUGIN> <ALIAS>"
+            opts="-j -v -h --log-level --jobs --prefer-installed --verbose --help <PLUGIN> <ALIAS>"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -682,7 +727,7 @@ _rtx() {
             return 0
             ;;
         rtx__alias__ls)
-            opts="-p -j -v -h --plugin --log-level --jobs --verbose --help"
+            opts="-p -j -v -h --plugin --log-level --jobs --prefer-installed --verbose --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -716,7 +761,7 @@ _rtx() {
             return 0
             ;;
         rtx__alias__set)
-            opts="-j -v -h --log-level --jobs --verbose --help <PLUGIN> <ALIAS> <VALUE>"
+            opts="-j -v -h --log-level --jobs --prefer-installed --verbose --help <PLUGIN> <ALIAS> <VALUE>"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -742,7 +787,7 @@ _rtx() {
             return 0
             ;;
         rtx__alias__unset)
-            opts="-j -v -h --log-level --jobs --verbose --help <PLUGIN> <ALIAS>"
+            opts="-j -v -h --log-level --jobs --prefer-installed --verbose --help <PLUGIN> <ALIAS>"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -768,7 +813,7 @@ _rtx() {
             return 0
             ;;
         rtx__asdf)
-            opts="-j -v -h --log-level --jobs --verbose --help [ARGS]..."
+            opts="-j -v -h --log-level --jobs --prefer-installed --verbose --help [ARGS]..."
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -794,7 +839,7 @@ _rtx() {
             return 0
             ;;
         rtx__bin__paths)
-            opts="-j -v -h --log-level --jobs --verbose --help"
+            opts="-j -v -h --log-level --jobs --prefer-installed --verbose --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -820,7 +865,7 @@ _rtx() {
             return 0
             ;;
         rtx__cache)
-            opts="-j -v -h --log-level --jobs --verbose --help clear help"
+            opts="-j -v -h --log-level --jobs --prefer-installed --verbose --help clear help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -846,7 +891,7 @@ _rtx() {
             return 0
             ;;
         rtx__cache__clear)
-            opts="-j -v -h --log-level --jobs --verbose --help"
+            opts="-j -v -h --log-level --jobs --prefer-installed --verbose --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -914,7 +959,7 @@ _rtx() {
             return 0
             ;;
         rtx__complete)
-            opts="-s -j -v -h --shell --log-level --jobs --verbose --help"
+            opts="-s -j -v -h --shell --log-level --jobs --prefer-installed --verbose --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -948,7 +993,7 @@ _rtx() {
             return 0
             ;;
         rtx__current)
-            opts="-j -v -h --log-level --jobs --verbose --help [PLUGIN]"
+            opts="-j -v -h --log-level --jobs --prefer-installed --verbose --help [PLUGIN]"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -974,7 +1019,7 @@ _rtx() {
             return 0
             ;;
         rtx__deactivate)
-            opts="-j -v -h --log-level --jobs --verbose --help"
+            opts="-j -v -h --log-level --jobs --prefer-installed --verbose --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -1000,7 +1045,7 @@ _rtx() {
             return 0
             ;;
         rtx__direnv)
-            opts="-j -v -h --log-level --jobs --verbose --help envrc exec activate help"
+            opts="-j -v -h --log-level --jobs --prefer-installed --verbose --help envrc exec activate help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -1026,7 +1071,7 @@ _rtx() {
             return 0
             ;;
         rtx__direnv__activate)
-            opts="-j -v -h --log-level --jobs --verbose --help"
+            opts="-j -v -h --log-level --jobs --prefer-installed --verbose --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -1052,7 +1097,7 @@ _rtx() {
             return 0
             ;;
         rtx__direnv__envrc)
-            opts="-j -v -h --log-level --jobs --verbose --help"
+            opts="-j -v -h --log-level --jobs --prefer-installed --verbose --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -1078,7 +1123,7 @@ _rtx() {
             return 0
             ;;
         rtx__direnv__exec)
-            opts="-j -v -h --log-level --jobs --verbose --help"
+            opts="-j -v -h --log-level --jobs --prefer-installed --verbose --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -1174,7 +1219,7 @@ _rtx() {
             return 0
             ;;
         rtx__doctor)
-            opts="-j -v -h --log-level --jobs --verbose --help"
+            opts="-j -v -h --log-level --jobs --prefer-installed --verbose --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -1200,7 +1245,7 @@ _rtx() {
             return 0
             ;;
         rtx__env)
-            opts="-s -j -v -h --shell --log-level --jobs --verbose --help [RUNTIME]..."
+            opts="-s -j -v -h --shell --log-level --jobs --prefer-installed --verbose --help [RUNTIME]..."
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -1234,7 +1279,7 @@ _rtx() {
             return 0
             ;;
         rtx__exec)
-            opts="-c -j -v -h --command --log-level --jobs --verbose --help [RUNTIME]... [COMMAND]..."
+            opts="-c -j -v -h --command --log-level --jobs --prefer-installed --verbose --help [RUNTIME]... [COMMAND]..."
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -1268,7 +1313,7 @@ _rtx() {
             return 0
             ;;
         rtx__global)
-            opts="-j -v -h --pin --fuzzy --remove --log-level --jobs --verbose --help [RUNTIME]..."
+            opts="-j -v -h --pin --fuzzy --remove --log-level --jobs --prefer-installed --verbose --help [RUNTIME]..."
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -1298,7 +1343,7 @@ _rtx() {
             return 0
             ;;
         rtx__help)
-            opts="activate alias asdf bin-paths cache complete current deactivate direnv doctor env exec global hook-env implode install latest local ls ls-remote mangen plugins reshim self-update settings shell uninstall version where which render-help help"
+            opts="activate alias asdf bin-paths cache complete current deactivate direnv doctor env exec global hook-env implode install latest local ls ls-remote outdated mangen plugins prune reshim self-update settings shell uninstall upgrade version where which render-help help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -1731,8 +1776,22 @@ _rtx() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        rtx__help__outdated)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         rtx__help__plugins)
-            opts="install ls ls-remote uninstall update"
+            opts="history info install ls ls-remote rollback uninstall update"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -1745,6 +1804,34 @@ _rtx() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        rtx__help__plugins__history)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        rtx__help__plugins__info)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         rtx__help__plugins__install)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
@@ -1787,6 +1874,20 @@ _rtx() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        rtx__help__plugins__rollback)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         rtx__help__plugins__uninstall)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
@@ -1815,6 +1916,20 @@ _rtx() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        rtx__help__prune)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         rtx__help__render__help)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
@@ -1955,6 +2070,20 @@ _rtx() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        rtx__help__upgrade)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         rtx__help__version)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
@@ -1998,7 +2127,7 @@ _rtx() {
             return 0
             ;;
         rtx__hook__env)
-            opts="-s -j -v -h --shell --status --log-level --jobs --verbose --help"
+            opts="-s -j -v -h --shell --status --log-level --jobs --prefer-installed --verbose --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2032,7 +2161,7 @@ _rtx() {
             return 0
             ;;
         rtx__implode)
-            opts="-j -v -h --config --dry-run --log-level --jobs --verbose --help"
+            opts="-j -v -h --config --dry-run --log-level --jobs --prefer-installed --verbose --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2058,7 +2187,7 @@ _rtx() {
             return 0
             ;;
         rtx__install)
-            opts="-p -f -a -v -j -h --plugin --force --all --verbose --log-level --jobs --help [RUNTIME]..."
+            opts="-p -f -a -v -j -h --plugin --force --all --verbose --frozen --log-level --jobs --prefer-installed --help [RUNTIME]..."
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2092,7 +2221,7 @@ _rtx() {
             return 0
             ;;
         rtx__latest)
-            opts="-j -v -h --log-level --jobs --verbose --help <RUNTIME> [ASDF_VERSION]"
+            opts="-j -v -h --log-level --jobs --prefer-installed --verbose --help <RUNTIME> [ASDF_VERSION]"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2118,7 +2247,7 @@ _rtx() {
             return 0
             ;;
         rtx__local)
-            opts="-p -j -v -h --parent --pin --fuzzy --remove --log-level --jobs --verbose --help [RUNTIME]..."
+            opts="-p -j -v -h --parent --pin --fuzzy --remove --log-level --jobs --prefer-installed --verbose --help [RUNTIME]..."
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2148,7 +2277,7 @@ _rtx() {
             return 0
             ;;
         rtx__ls)
-            opts="-p -c -j -v -h --plugin --current --log-level --jobs --verbose --help [PLUGIN_ARG]"
+            opts="-p -c -l -j -v -h --plugin --current --long --log-level --jobs --prefer-installed --verbose --help [PLUGIN_ARG]"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2182,7 +2311,7 @@ _rtx() {
             return 0
             ;;
         rtx__ls__remote)
-            opts="-j -v -h --log-level --jobs --verbose --help <PLUGIN> [PREFIX]"
+            opts="-j -v -h --prerelease --log-level --jobs --prefer-installed --verbose --help <PLUGIN> [PREFIX]"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2208,7 +2337,33 @@ _rtx() {
             return 0
             ;;
         rtx__mangen)
-            opts="-j -v -h --log-level --jobs --verbose --help"
+            opts="-j -v -h --log-level --jobs --prefer-installed --verbose --help"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --log-level)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --jobs)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                -j)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        rtx__outdated)
+            opts="-j -v -h --json --exit-code --log-level --jobs --prefer-installed --verbose --help [RUNTIME]..."
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2234,7 +2389,7 @@ _rtx() {
             return 0
             ;;
         rtx__plugins)
-            opts="-a -u -j -v -h --all --urls --log-level --jobs --verbose --help install ls ls-remote uninstall update help"
+            opts="-a -u -j -v -h --all --urls --log-level --jobs --prefer-installed --verbose --help history info install ls ls-remote rollback uninstall update help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2260,7 +2415,7 @@ _rtx() {
             return 0
             ;;
         rtx__plugins__help)
-            opts="install ls ls-remote uninstall update help"
+            opts="history info install ls ls-remote rollback uninstall update help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2287,6 +2442,34 @@ _rtx() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        rtx__plugins__help__history)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        rtx__plugins__help__info)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         rtx__plugins__help__install)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
@@ -2329,6 +2512,20 @@ _rtx() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        rtx__plugins__help__rollback)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         rtx__plugins__help__uninstall)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
@@ -2357,8 +2554,60 @@ _rtx() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        rtx__plugins__history)
+            opts="-j -v -h --log-level --jobs --prefer-installed --verbose --help <PLUGIN>"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --log-level)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --jobs)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                -j)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        rtx__plugins__info)
+            opts="-j -v -h --log-level --jobs --prefer-installed --verbose --help <PLUGIN>"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --log-level)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --jobs)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                -j)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         rtx__plugins__install)
-            opts="-f -a -v -j -h --force --all --verbose --log-level --jobs --help [NAME] [GIT_URL]"
+            opts="-f -a -v -j -h --force --all --verbose --log-level --jobs --prefer-installed --help [NAME] [GIT_URL]"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2384,7 +2633,7 @@ _rtx() {
             return 0
             ;;
         rtx__plugins__ls)
-            opts="-a -u -j -v -h --all --urls --log-level --jobs --verbose --help"
+            opts="-a -u -j -v -h --all --urls --log-level --jobs --prefer-installed --verbose --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2410,7 +2659,7 @@ _rtx() {
             return 0
             ;;
         rtx__plugins__ls__remote)
-            opts="-u -j -v -h --urls --log-level --jobs --verbose --help"
+            opts="-u -j -v -h --urls --log-level --jobs --prefer-installed --verbose --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2435,8 +2684,38 @@ _rtx() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        rtx__plugins__rollback)
+            opts="-j -v -h --to --log-level --jobs --prefer-installed --verbose --help <PLUGIN>"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --to)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --log-level)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --jobs)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                -j)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         rtx__plugins__uninstall)
-            opts="-j -v -h --log-level --jobs --verbose --help <PLUGIN>"
+            opts="-j -v -h --log-level --jobs --prefer-installed --verbose --help <PLUGIN>"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2462,7 +2741,7 @@ _rtx() {
             return 0
             ;;
         rtx__plugins__update)
-            opts="-a -j -v -h --all --log-level --jobs --verbose --help [PLUGIN]..."
+            opts="-a -j -v -h --all --unpin --log-level --jobs --prefer-installed --verbose --help [PLUGIN]..."
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2487,8 +2766,34 @@ _rtx() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        rtx__prune)
+            opts="-j -v -h --dry-run --log-level --jobs --prefer-installed --verbose --help [PLUGIN]..."
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --log-level)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --jobs)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                -j)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         rtx__render__help)
-            opts="-j -v -h --log-level --jobs --verbose --help"
+            opts="-j -v -h --log-level --jobs --prefer-installed --verbose --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2514,7 +2819,7 @@ _rtx() {
             return 0
             ;;
         rtx__reshim)
-            opts="-j -v -h --log-level --jobs --verbose --help [PLUGIN] [VERSION]"
+            opts="-j -v -h --log-level --jobs --prefer-installed --verbose --help [PLUGIN] [VERSION]"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2540,7 +2845,7 @@ _rtx() {
             return 0
             ;;
         rtx__self__update)
-            opts="-j -v -h --log-level --jobs --verbose --help"
+            opts="-j -v -h --log-level --jobs --prefer-installed --verbose --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2566,7 +2871,7 @@ _rtx() {
             return 0
             ;;
         rtx__settings)
-            opts="-j -v -h --log-level --jobs --verbose --help get ls set unset help"
+            opts="-j -v -h --log-level --jobs --prefer-installed --verbose --help get ls set unset help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2592,7 +2897,7 @@ _rtx() {
             return 0
             ;;
         rtx__settings__get)
-            opts="-j -v -h --log-level --jobs --verbose --help <KEY>"
+            opts="-j -v -h --log-level --jobs --prefer-installed --verbose --help <KEY>"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2702,7 +3007,7 @@ _rtx() {
             return 0
             ;;
         rtx__settings__ls)
-            opts="-j -v -h --log-level --jobs --verbose --help"
+            opts="-j -v -h --log-level --jobs --prefer-installed --verbose --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2728,7 +3033,7 @@ _rtx() {
             return 0
             ;;
         rtx__settings__set)
-            opts="-j -v -h --log-level --jobs --verbose --help <KEY> <VALUE>"
+            opts="-j -v -h --log-level --jobs --prefer-installed --verbose --help <KEY> <VALUE>"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2754,7 +3059,7 @@ _rtx() {
             return 0
             ;;
         rtx__settings__unset)
-            opts="-j -v -h --log-level --jobs --verbose --help <KEY>"
+            opts="-j -v -h --log-level --jobs --prefer-installed --verbose --help <KEY>"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2780,7 +3085,7 @@ _rtx() {
             return 0
             ;;
         rtx__shell)
-            opts="-j -v -h --log-level --jobs --verbose --help [RUNTIME]..."
+            opts="-j -v -h --log-level --jobs --prefer-installed --verbose --help [RUNTIME]..."
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2806,7 +3111,37 @@ _rtx() {
             return 0
             ;;
         rtx__uninstall)
-            opts="-j -v -h --log-level --jobs --verbose --help <RUNTIME>..."
+            opts="-j -v -h --unused-for --log-level --jobs --prefer-installed --verbose --help [RUNTIME]..."
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --unused-for)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --log-level)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --jobs)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                -j)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        rtx__upgrade)
+            opts="-j -v -h --bump --dry-run --log-level --jobs --prefer-installed --verbose --help [PLUGIN]..."
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2832,7 +3167,7 @@ _rtx() {
             return 0
             ;;
         rtx__version)
-            opts="-j -v -h --log-level --jobs --verbose --help"
+            opts="-j -v -h --log-level --jobs --prefer-installed --verbose --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2858,7 +3193,7 @@ _rtx() {
             return 0
             ;;
         rtx__where)
-            opts="-j -v -h --log-level --jobs --verbose --help <RUNTIME> [ASDF_VERSION]"
+            opts="-j -v -h --log-level --jobs --prefer-installed --verbose --help <RUNTIME> [ASDF_VERSION]"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2884,7 +3219,7 @@ _rtx() {
             return 0
             ;;
         rtx__which)
-            opts="-j -v -h --log-level --jobs --verbose --help <BIN_NAME>"
+            opts="-j -v -h --log-level --jobs --prefer-installed --verbose --help <BIN_NAME>"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
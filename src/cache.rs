@@ -3,7 +3,7 @@ use std::fs;
 use std::fs::File;
 use std::io::{Read, Write};
 use std::path::PathBuf;
-use std::time::Duration;
+use std::time::{Duration, SystemTime};
 
 use color_eyre::eyre::Result;
 use flate2::read::ZlibDecoder;
@@ -70,6 +70,27 @@ where
         Ok(val)
     }
 
+    /// like get_or_try_init but uses the cache file even if it is stale
+    /// and never writes to it, for when fetching is not possible (e.g.: offline)
+    pub fn get_or_try_init_stale<F>(&self, fallback: F) -> Result<&T>
+    where
+        F: FnOnce() -> Result<T>,
+    {
+        let val = self.cache.get_or_try_init(|| {
+            let path = &self.cache_file_path;
+            if path.exists() {
+                match self.parse() {
+                    Ok(val) => return Ok::<_, color_eyre::Report>(val),
+                    Err(err) => {
+                        warn!("failed to parse cache file: {} {}", path.display(), err);
+                    }
+                }
+            }
+            (fallback)()
+        })?;
+        Ok(val)
+    }
+
     fn parse(&self) -> Result<T> {
         let path = &self.cache_file_path;
         trace!("reading cache {}", path.display());
@@ -100,6 +121,13 @@ where
         Ok(())
     }
 
+    /// when the cache file was last written and whether it is still fresh,
+    /// None if there is no cache file
+    pub fn status(&self) -> Option<(SystemTime, bool)> {
+        let modified = self.cache_file_path.metadata().ok()?.modified().ok()?;
+        Some((modified, self.is_fresh()))
+    }
+
     fn is_fresh(&self) -> bool {
         if !self.cache_file_path.exists() {
             return false;
@@ -145,4 +173,20 @@ mod tests {
         let val = cache.get_or_try_init(|| Ok(2)).unwrap();
         assert_eq!(val, &1);
     }
+
+    #[test]
+    fn test_cache_stale() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("cache.msgpack.zlib");
+        let cache = CacheManager::new(path.clone());
+        let val = cache.get_or_try_init_stale(|| Ok(1)).unwrap();
+        assert_eq!(val, &1);
+        assert!(!path.exists());
+
+        CacheManager::new(path.clone()).write(2).unwrap();
+        let cache = CacheManager::new(path).with_fresh_duration(Some(Duration::ZERO));
+        let val = cache.get_or_try_init_stale(|| Ok(3)).unwrap();
+        assert_eq!(val, &2);
+        assert!(matches!(cache.status(), Some((_, false))));
+    }
 }
@@ -1,4 +1,5 @@
 pub mod jobs;
 pub mod log_level;
+pub mod prefer_installed;
 pub mod runtime;
 pub mod verbose;
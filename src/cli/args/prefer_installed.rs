@@ -0,0 +1,14 @@
+use clap::{Arg, ArgAction};
+
+#[derive(Clone)]
+pub struct PreferInstalled;
+
+impl PreferInstalled {
+    pub fn arg() -> clap::Arg {
+        Arg::new("prefer-installed")
+            .long("prefer-installed")
+            .help("Resolve fuzzy versions to the highest installed match before checking remote versions")
+            .global(true)
+            .action(ArgAction::SetTrue)
+    }
+}
@@ -24,7 +24,7 @@ impl Command for Doctor {
             }
         }
 
-        if let Some(latest) = cli::version::check_for_new_version() {
+        if let Some(latest) = cli::version::check_for_new_version(&config.settings) {
             warn!(
                 "new rtx version {} available, currently on {}",
                 latest,
@@ -16,7 +16,7 @@ use crate::config::Config;
 use crate::config::MissingRuntimeBehavior::Ignore;
 use crate::env;
 use crate::output::Output;
-use crate::toolset::ToolsetBuilder;
+use crate::toolset::{Toolset, ToolsetBuilder};
 
 /// Execute a command with runtime(s) set
 ///
@@ -52,8 +52,10 @@ impl Command for Exec {
             .with_args(&self.runtime)
             .with_install_missing()
             .build(&config);
+        ts.touch_last_used();
 
         let (program, args) = parse_command(&env::SHELL, self.command, self.c);
+        let program = which(&ts, program)?;
         let mut env = ts.env();
         env.insert("PATH".into(), ts.path_env());
         if config.settings.missing_runtime_behavior != Ignore {
@@ -101,6 +103,22 @@ where
     }
 }
 
+/// resolves the program with the toolset so the plugin's bin/exec-path is used, anything that is
+/// not a runtime's executable is left for the OS to find on PATH
+fn which(ts: &Toolset, program: OsString) -> Result<OsString> {
+    let bin_name = match program.to_str() {
+        Some(bin_name) if !bin_name.contains('/') => bin_name,
+        _ => return Ok(program),
+    };
+    match ts.which(bin_name) {
+        Some(rtv) => Ok(rtv
+            .which(bin_name)?
+            .map(|path| path.into_os_string())
+            .unwrap_or(program)),
+        None => Ok(program),
+    }
+}
+
 fn parse_command(
     shell: &str,
     command: Option<Vec<OsString>>,
@@ -37,6 +37,7 @@ impl Command for HookEnv {
             config.settings.missing_runtime_behavior = Warn;
         }
         let ts = ToolsetBuilder::new().with_install_missing().build(&config);
+        ts.touch_last_used();
 
         let shell = get_shell(self.shell).expect("no shell provided, use `--shell=zsh`");
         out.stdout.write(hook_env::clear_old_env(&*shell));
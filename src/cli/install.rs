@@ -10,6 +10,7 @@ use crate::cli::command::Command;
 use crate::config::Config;
 use crate::config::MissingRuntimeBehavior::AutoInstall;
 use crate::errors::Error::PluginNotInstalled;
+use crate::lockfile;
 use crate::output::Output;
 use crate::plugins::PluginName;
 use crate::toolset::ToolsetBuilder;
@@ -48,6 +49,12 @@ pub struct Install {
     /// Show installation output
     #[clap(long, short, action = clap::ArgAction::Count)]
     verbose: u8,
+
+    /// Error if there is no lockfile, a runtime is not in it, or a plugin is not at the locked sha
+    ///
+    /// Lockfiles will not be updated
+    #[clap(long, conflicts_with = "runtime", verbatim_doc_comment)]
+    frozen: bool,
 }
 
 impl Command for Install {
@@ -84,7 +91,7 @@ impl Install {
                     if let Some(rtv) = &v.rtv {
                         if rtv.is_installed() {
                             info!("uninstalling {}", rtv);
-                            rtv.uninstall()?;
+                            rtv.uninstall(&config.settings)?;
                         }
                     }
                 }
@@ -97,6 +104,9 @@ impl Install {
 
     fn install_missing_runtimes(&self, config: &Config) -> Result<()> {
         let mut ts = ToolsetBuilder::new().build(config);
+        if self.frozen {
+            lockfile::verify(config, &ts)?;
+        }
         if let Some(plugins) = &self.plugin {
             let plugins = plugins.iter().collect::<HashSet<&PluginName>>();
             for plugin in ts.versions.keys().cloned().collect::<Vec<_>>() {
@@ -111,6 +121,9 @@ impl Install {
             }
         }
         ts.install_missing(config)?;
+        if !self.frozen {
+            lockfile::update(config, &ts)?;
+        }
 
         Ok(())
     }
@@ -124,6 +137,7 @@ static AFTER_LONG_HELP: Lazy<String> = Lazy::new(|| {
       $ rtx install nodejs         # install version specified in .tool-versions
       $ rtx install                # installs all runtimes specified in .tool-versions for installed plugins
       $ rtx install --all          # installs all runtimes and all plugins
+      $ rtx install --frozen       # installs the exact versions in .tool-versions.lock
     "#, style("Examples:").bold().underlined()}
 });
 
@@ -149,6 +163,16 @@ mod tests {
         assert_cli_snapshot!("where", "tiny@my/alias");
     }
 
+    #[test]
+    fn test_install_range() {
+        assert_cli!("install", "tiny@>=2 <3");
+        let output = assert_cli!("where", "tiny@>=2 <3");
+        assert_str_eq!(
+            output.trim(),
+            dirs::INSTALLS.join("tiny/2.1.0").to_string_lossy()
+        );
+    }
+
     #[test]
     fn test_install_ref() {
         assert_cli!("install", "-f", "dummy@ref:master");
@@ -25,6 +25,7 @@ pub struct Latest {
 
 impl Command for Latest {
     fn run(self, config: Config, out: &mut Output) -> Result<()> {
+        config.settings.ensure_online("fetch the latest version")?;
         let prefix = match self.runtime.version {
             RuntimeArgVersion::None => match self.asdf_version {
                 Some(version) => version,
@@ -8,7 +8,7 @@ use crate::cli::command::Command;
 use crate::config::{config_file, Config};
 use crate::output::Output;
 use crate::plugins::PluginName;
-use crate::{dirs, env, file};
+use crate::{dirs, env, file, lockfile};
 
 /// Sets .tool-versions to include a specific runtime
 ///
@@ -87,6 +87,12 @@ impl Command for Local {
 
         if self.runtime.is_some() || self.remove.is_some() {
             cf.save()?;
+            if lockfile::is_enabled(&config, &cf_path) {
+                let mut ts = cf.to_toolset().with_plugins(config.plugins.clone());
+                lockfile::apply(&mut ts);
+                ts.resolve(&config);
+                lockfile::update(&config, &ts)?;
+            }
         }
 
         rtxprint!(out, "{}", cf.dump());
@@ -146,7 +152,7 @@ mod tests {
     fn test_local_pin() {
         run_test(|| {
             let stdout = assert_cli!("local", "--pin", "tiny@1");
-            assert_str_eq!(grep(stdout, "tiny"), "tiny 1.0.1");
+            assert_str_eq!(grep(stdout, "tiny"), "tiny 1.1.0");
             let stdout = assert_cli!("local", "--pin", "tiny", "2");
             assert_str_eq!(grep(stdout, "tiny"), "tiny 2.1.0");
         });
@@ -202,6 +208,26 @@ mod tests {
         });
     }
     #[test]
+    fn test_local_lockfile() {
+        let lockfile = dirs::CURRENT.join(".test-tool-versions.lock");
+        fs::write(&lockfile, "").unwrap();
+        run_test(|| {
+            assert_cli!("local", "tiny@2");
+            assert_cli!("install", "--frozen");
+            let locked = fs::read_to_string(dirs::CURRENT.join(".test-tool-versions.lock"));
+            assert_str_eq!(grep(locked.unwrap(), "2 ="), r#"2 = "2.1.0""#);
+            fs::write(dirs::CURRENT.join(".test-tool-versions"), "tiny 1\n").unwrap();
+            let err = assert_cli_err!("install", "--frozen");
+            assert_str_eq!(err.to_string(), "tiny@1 from ~/cwd/.test-tool-versions is not in ~/cwd/.test-tool-versions.lock. Run `rtx install` without --frozen to update it");
+            fs::remove_file(&lockfile).unwrap();
+            assert_cli!("local", "tiny@2");
+            assert!(!lockfile.exists());
+            let err = assert_cli_err!("install", "--frozen");
+            assert_str_eq!(err.to_string(), "no lockfile found for ~/cwd/.test-tool-versions. Run `rtx install` with RTX_LOCKFILE=1 to create one");
+        });
+        let _ = fs::remove_file(lockfile);
+    }
+    #[test]
     fn test_local_alias_ref() {
         run_test(|| {
             assert_cli!("alias", "set", "dummy", "m", "ref:master");
@@ -1,6 +1,8 @@
 use std::cmp::max;
 use std::collections::HashMap;
+use std::time::SystemTime;
 
+use chrono::{DateTime, Local};
 use color_eyre::eyre::Result;
 use console::style;
 use indoc::formatdoc;
@@ -34,6 +36,10 @@ pub struct Ls {
     /// Only show runtimes currently specified in .tool-versions
     #[clap(long, short)]
     current: bool,
+
+    /// Also show when each runtime was installed and last used
+    #[clap(long, short)]
+    long: bool,
 }
 
 impl Command for Ls {
@@ -45,7 +51,7 @@ impl Command for Ls {
             }
             rtxprintln!(
                 out,
-                "{} {} {}",
+                "{} {} {}{}",
                 match rtv.is_installed() && source.is_some() {
                     true =>
                         if *DUMB_TERMINAL {
@@ -56,6 +62,14 @@ impl Command for Ls {
                     false => "  ",
                 },
                 styled_version(&rtv, !rtv.is_installed(), source.is_some()),
+                match self.long {
+                    true => format!(
+                        "installed {:<10}  last used {:<10} ",
+                        format_date(rtv.installed_at()),
+                        format_date(rtv.last_used()),
+                    ),
+                    false => "".into(),
+                },
                 match source {
                     Some(source) => format!("(set by {source})"),
                     None => "".into(),
@@ -91,6 +105,13 @@ fn styled_version(rtv: &RuntimeVersion, missing: bool, active: bool) -> String {
     )
 }
 
+fn format_date(time: Option<SystemTime>) -> String {
+    match time {
+        Some(time) => DateTime::<Local>::from(time).format("%Y-%m-%d").to_string(),
+        None => "never".into(),
+    }
+}
+
 fn get_runtime_list(
     config: &Config,
     plugin_flag: &Option<PluginName>,
@@ -154,6 +175,10 @@ static AFTER_LONG_HELP: Lazy<String> = Lazy::new(|| {
       $ rtx list --current
       -> nodejs     18.0.0 (set by ~/src/myapp/.tool-versions)
       -> python     3.11.0 (set by ~/.tool-versions)
+
+      $ rtx list --long
+      -> nodejs     18.0.0 installed 2023-01-14  last used 2023-02-10 (set by ~/src/myapp/.tool-versions)
+         python     3.10.0 installed 2022-11-02  last used never
     "#, style("Examples:").bold().underlined()}
 });
 
@@ -180,4 +205,12 @@ mod tests {
         assert_cli!("install");
         assert_cli_snapshot!("list");
     }
+
+    #[test]
+    fn test_ls_long() {
+        assert_cli!("exec", "--", "true");
+        let stdout = assert_cli!("list", "--long", "tiny");
+        assert!(stdout.contains("installed 2"));
+        assert!(stdout.contains("last used 2"));
+    }
 }
@@ -13,6 +13,9 @@ use crate::output::Output;
 ///
 /// note that these versions are cached for commands like `rtx install nodejs@latest`
 /// however _this_ command will always clear that cache and fetch the latest remote versions
+///
+/// prereleases (e.g.: 1.0.0-rc1) are hidden unless `--prerelease` is passed
+/// or `include_prereleases` is enabled in settings
 #[derive(Debug, clap::Args)]
 #[clap(visible_alias = "list-remote", verbatim_doc_comment, after_long_help = AFTER_LONG_HELP.as_str(), alias = "list-all")]
 pub struct LsRemote {
@@ -24,10 +27,15 @@ pub struct LsRemote {
     /// same as the first argument after the "@"
     #[clap()]
     prefix: Option<String>,
+
+    /// Include prerelease versions (e.g.: 1.0.0-rc1)
+    #[clap(long)]
+    prerelease: bool,
 }
 
 impl Command for LsRemote {
     fn run(self, config: Config, out: &mut Output) -> Result<()> {
+        config.settings.ensure_online("list remote versions")?;
         let plugin = config
             .plugins
             .get(&self.plugin.plugin)
@@ -39,7 +47,9 @@ impl Command for LsRemote {
             _ => self.prefix,
         };
 
-        let versions = plugin.list_remote_versions(&config.settings)?.clone();
+        let include_prereleases = self.prerelease || plugin.include_prereleases(&config.settings);
+        let versions =
+            plugin.list_remote_versions_filtered(&config.settings, include_prereleases)?;
         let versions = match prefix {
             Some(prefix) => versions
                 .into_iter()
@@ -47,6 +57,7 @@ impl Command for LsRemote {
                 .collect(),
             None => versions,
         };
+        plugin.autoupdate(&config);
 
         for version in versions {
             rtxprintln!(out, "{}", version);
@@ -70,19 +81,47 @@ static AFTER_LONG_HELP: Lazy<String> = Lazy::new(|| {
       $ rtx ls-remote nodejs 18
       18.0.0
       18.1.0
+
+      $ rtx ls-remote --prerelease nodejs 20
+      20.0.0-rc.1
+      20.0.0
     "#, style("Examples:").bold().underlined()}
 });
 
 #[cfg(test)]
 mod tests {
+    use std::fs;
+    use std::os::unix::fs::PermissionsExt;
 
-    use crate::assert_cli_snapshot;
+    use pretty_assertions::assert_str_eq;
+
+    use crate::dirs;
+    use crate::test::CleanupPlugins;
+    use crate::{assert_cli, assert_cli_snapshot};
 
     #[test]
     fn test_list_remote() {
         assert_cli_snapshot!("list-remote", "dummy");
     }
 
+    #[test]
+    fn test_ls_remote_prerelease() {
+        let _cleanup = CleanupPlugins(vec!["prerelease-test"]);
+        let plugin_path = dirs::PLUGINS.join("prerelease-test");
+        let list_all = plugin_path.join("bin/list-all");
+        fs::create_dir_all(list_all.parent().unwrap()).unwrap();
+        fs::write(
+            &list_all,
+            "#!/usr/bin/env bash\necho 1.0.0 2.0.0-rc1 2.0.0\n",
+        )
+        .unwrap();
+        fs::set_permissions(&list_all, fs::Permissions::from_mode(0o755)).unwrap();
+        let stable = assert_cli!("list-remote", "prerelease-test@2");
+        assert_str_eq!(stable, "2.0.0\n");
+        let prerelease = assert_cli!("list-remote", "--prerelease", "prerelease-test@2");
+        assert_str_eq!(prerelease, "2.0.0-rc1\n2.0.0\n");
+    }
+
     #[test]
     fn test_ls_remote_prefix() {
         assert_cli_snapshot!("list-remote", "dummy", "1");
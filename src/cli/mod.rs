@@ -32,12 +32,15 @@ mod latest;
 mod local;
 mod ls;
 mod ls_remote;
+mod outdated;
 mod plugins;
+mod prune;
 mod reshim;
 mod self_update;
 mod settings;
 mod shell;
 mod uninstall;
+mod upgrade;
 pub mod version;
 mod r#where;
 mod which;
@@ -76,14 +79,17 @@ pub enum Commands {
     Local(local::Local),
     Ls(ls::Ls),
     LsRemote(ls_remote::LsRemote),
+    Outdated(outdated::Outdated),
     #[cfg(feature = "clap_mangen")]
     Mangen(mangen::Mangen),
     Plugins(plugins::Plugins),
+    Prune(prune::Prune),
     Reshim(reshim::Reshim),
     SelfUpdate(self_update::SelfUpdate),
     Settings(settings::Settings),
     Shell(shell::Shell),
     Uninstall(uninstall::Uninstall),
+    Upgrade(upgrade::Upgrade),
     Version(version::Version),
     Where(r#where::Where),
     Which(which::Which),
@@ -115,14 +121,17 @@ impl Commands {
             Self::Local(cmd) => cmd.run(config, out),
             Self::Ls(cmd) => cmd.run(config, out),
             Self::LsRemote(cmd) => cmd.run(config, out),
+            Self::Outdated(cmd) => cmd.run(config, out),
             #[cfg(feature = "clap_mangen")]
             Self::Mangen(cmd) => cmd.run(config, out),
             Self::Plugins(cmd) => cmd.run(config, out),
+            Self::Prune(cmd) => cmd.run(config, out),
             Self::Reshim(cmd) => cmd.run(config, out),
             Self::SelfUpdate(cmd) => cmd.run(config, out),
             Self::Settings(cmd) => cmd.run(config, out),
             Self::Shell(cmd) => cmd.run(config, out),
             Self::Uninstall(cmd) => cmd.run(config, out),
+            Self::Upgrade(cmd) => cmd.run(config, out),
             Self::Version(cmd) => cmd.run(config, out),
             Self::Where(cmd) => cmd.run(config, out),
             Self::Which(cmd) => cmd.run(config, out),
@@ -161,6 +170,7 @@ impl Cli {
                 .after_help(AFTER_HELP.as_str())
                 .arg(args::log_level::LogLevel::arg())
                 .arg(args::jobs::Jobs::arg())
+                .arg(args::prefer_installed::PreferInstalled::arg())
                 .arg(args::verbose::Verbose::arg()),
         )
     }
@@ -184,6 +194,9 @@ impl Cli {
         if let Some(jobs) = matches.get_one::<usize>("jobs") {
             config.settings.jobs = *jobs;
         }
+        if matches.get_flag("prefer-installed") {
+            config.settings.prefer_installed = true;
+        }
         if *matches.get_one::<u8>("verbose").unwrap() > 0 {
             config.settings.verbose = true;
         }
@@ -0,0 +1,198 @@
+use std::collections::HashSet;
+
+use color_eyre::eyre::{eyre, Result};
+use console::style;
+use indoc::formatdoc;
+use itertools::Itertools;
+use once_cell::sync::Lazy;
+use serde_derive::Serialize;
+use versions::Versioning;
+
+use crate::cli::args::runtime::{RuntimeArg, RuntimeArgParser};
+use crate::cli::command::Command;
+use crate::config::Config;
+use crate::output::Output;
+use crate::plugins::{InstallType, PluginName};
+use crate::toolset::ToolsetBuilder;
+
+/// Shows runtimes that have newer versions available
+///
+/// For each active runtime this compares the version it resolved to with the latest
+/// version that matches what was requested (e.g.: `nodejs 18` -> the latest 18.x)
+/// and with the latest version overall.
+#[derive(Debug, clap::Args)]
+#[clap(verbatim_doc_comment, after_long_help = AFTER_LONG_HELP.as_str())]
+pub struct Outdated {
+    /// Runtimes to check, if not specified all active runtimes are checked
+    ///
+    /// e.g.: nodejs, nodejs@18
+    #[clap(value_parser = RuntimeArgParser, verbatim_doc_comment)]
+    runtime: Vec<RuntimeArg>,
+
+    /// Output in JSON format
+    #[clap(long)]
+    json: bool,
+
+    /// Exit with a non-zero status if any runtimes are outdated, useful in CI
+    #[clap(long)]
+    exit_code: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct OutdatedRuntime {
+    plugin: PluginName,
+    requested: String,
+    current: String,
+    latest_in_range: Option<String>,
+    latest: Option<String>,
+    source: String,
+}
+
+impl Command for Outdated {
+    fn run(self, config: Config, out: &mut Output) -> Result<()> {
+        let ts = ToolsetBuilder::new()
+            .with_args(&self.runtime)
+            .build(&config);
+        let plugins = self
+            .runtime
+            .iter()
+            .map(|r| &r.plugin)
+            .collect::<HashSet<_>>();
+        let mut outdated = vec![];
+        for (plugin_name, tvl) in &ts.versions {
+            if !plugins.is_empty() && !plugins.contains(plugin_name) {
+                continue;
+            }
+            for tv in &tvl.versions {
+                let rtv = match &tv.rtv {
+                    // refs, paths, and system can't be compared with the remote versions
+                    Some(rtv) if matches!(rtv.install_type, InstallType::Version(_)) => rtv,
+                    _ => continue,
+                };
+                let latest_in_range = tv.latest_version(&config.settings, rtv.plugin.clone())?;
+                let latest = rtv.plugin.latest_version(&config.settings, "latest")?;
+                let newer = |v: &Option<String>| matches!(v, Some(v) if is_newer(v, &rtv.version));
+                if newer(&latest_in_range) || newer(&latest) {
+                    outdated.push(OutdatedRuntime {
+                        plugin: plugin_name.clone(),
+                        requested: tv.r#type.to_string(),
+                        current: rtv.version.clone(),
+                        latest_in_range,
+                        latest,
+                        source: tvl.source.to_string(),
+                    });
+                }
+            }
+        }
+
+        if self.json {
+            rtxprintln!(out, "{}", serde_json::to_string_pretty(&outdated)?);
+        } else if outdated.is_empty() {
+            info!("all runtimes are up to date");
+        } else {
+            display_table(out, &outdated);
+        }
+
+        if self.exit_code && !outdated.is_empty() {
+            let plural = if outdated.len() == 1 { "" } else { "s" };
+            return Err(eyre!("{} outdated runtime{plural}", outdated.len()));
+        }
+        Ok(())
+    }
+}
+
+pub fn is_newer(latest: &str, current: &str) -> bool {
+    Versioning::new(latest) > Versioning::new(current)
+}
+
+fn display_table(out: &mut Output, outdated: &[OutdatedRuntime]) {
+    let header = [
+        "Plugin",
+        "Requested",
+        "Current",
+        "Latest in range",
+        "Latest",
+        "Source",
+    ]
+    .map(String::from);
+    let rows = outdated
+        .iter()
+        .map(|o| {
+            [
+                o.plugin.clone(),
+                o.requested.clone(),
+                o.current.clone(),
+                o.latest_in_range.clone().unwrap_or_default(),
+                o.latest.clone().unwrap_or_default(),
+                o.source.clone(),
+            ]
+        })
+        .collect_vec();
+    let widths = (0..header.len())
+        .map(|i| {
+            rows.iter()
+                .chain([&header])
+                .map(|row| row[i].len())
+                .max()
+                .unwrap_or_default()
+        })
+        .collect_vec();
+    for row in [&header].into_iter().chain(&rows) {
+        let line = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:width$}"))
+            .join("  ");
+        rtxprintln!(out, "{}", line.trim_end());
+    }
+}
+
+static AFTER_LONG_HELP: Lazy<String> = Lazy::new(|| {
+    formatdoc! {r#"
+    {}
+      $ rtx outdated
+      Plugin  Requested  Current  Latest in range  Latest   Source
+      nodejs  18         18.0.0   18.14.0          19.6.0   ~/.tool-versions
+      python  3.11.0     3.11.0   3.11.0           3.12.0   ~/src/myapp/.tool-versions
+
+      $ rtx outdated nodejs --json   # show outdated nodejs versions as JSON
+      $ rtx outdated --exit-code     # exit with an error if anything is outdated
+    "#, style("Examples:").bold().underlined()}
+});
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_display_snapshot;
+
+    use crate::env;
+    use crate::{assert_cli, assert_cli_err, assert_cli_snapshot};
+
+    #[test]
+    fn test_outdated() {
+        assert_cli_snapshot!("outdated", "tiny@2");
+    }
+
+    #[test]
+    fn test_outdated_json() {
+        assert_cli_snapshot!("outdated", "tiny@2", "--json");
+    }
+
+    #[test]
+    fn test_outdated_up_to_date() {
+        let stdout = assert_cli!("outdated", "tiny@3", "--exit-code");
+        assert_eq!(stdout, "");
+    }
+
+    #[test]
+    fn test_outdated_skips_refs_and_paths() {
+        let path = format!("tiny@path:{}", env::HOME.display());
+        let stdout = assert_cli!("outdated", "tiny@ref:master", &path, "--exit-code");
+        assert_eq!(stdout, "");
+    }
+
+    #[test]
+    fn test_outdated_exit_code() {
+        let err = assert_cli_err!("outdated", "tiny@2", "--exit-code");
+        assert_display_snapshot!(err, @"1 outdated runtime");
+    }
+}
@@ -0,0 +1,67 @@
+use color_eyre::eyre::Result;
+use console::style;
+use indoc::formatdoc;
+use once_cell::sync::Lazy;
+
+use crate::cli::command::Command;
+use crate::config::Config;
+use crate::errors::Error::PluginNotInstalled;
+use crate::git::{short_sha, Git};
+use crate::output::Output;
+
+/// Shows the git shas a plugin has been at, newest first
+///
+/// these are recorded when the plugin is installed, updated, or rolled back
+/// and can be checked out again with `rtx plugins rollback`
+#[derive(Debug, clap::Args)]
+#[clap(verbatim_doc_comment, after_long_help = AFTER_LONG_HELP.as_str())]
+pub struct PluginsHistory {
+    /// Plugin to show the history of
+    #[clap()]
+    plugin: String,
+}
+
+impl Command for PluginsHistory {
+    fn run(self, config: Config, out: &mut Output) -> Result<()> {
+        let plugin = config
+            .plugins
+            .get(&self.plugin)
+            .ok_or(PluginNotInstalled(self.plugin))?;
+        let git = Git::new(plugin.plugin_path.clone());
+        let current = git.current_sha().ok();
+        for entry in plugin.history()?.iter().rev() {
+            let subject = git.subject(&entry.sha).unwrap_or_default();
+            let current = match current.as_ref() == Some(&entry.sha) {
+                true => style(" (current)").green().to_string(),
+                false => String::new(),
+            };
+            rtxprintln!(
+                out,
+                "{}  {}  {subject}{current}",
+                entry.date.format("%Y-%m-%d %H:%M"),
+                style(short_sha(&entry.sha)).yellow(),
+            );
+        }
+        Ok(())
+    }
+}
+
+static AFTER_LONG_HELP: Lazy<String> = Lazy::new(|| {
+    formatdoc! {r#"
+    {}
+      $ rtx plugins history nodejs
+      2023-02-20 10:12  1a2b3c4  fix install on macos (current)
+      2023-02-13 09:30  5d6e7f8  add lts aliases
+    "#, style("Examples:").bold().underlined()}
+});
+
+#[cfg(test)]
+mod tests {
+    use crate::assert_cli;
+
+    #[test]
+    fn test_plugin_history_not_git() {
+        let stdout = assert_cli!("plugins", "history", "tiny");
+        assert_eq!(stdout, "");
+    }
+}
@@ -0,0 +1,115 @@
+use std::time::SystemTime;
+
+use chrono::{DateTime, Local};
+use color_eyre::eyre::Result;
+use console::style;
+use indoc::formatdoc;
+use once_cell::sync::Lazy;
+
+use crate::cli::command::Command;
+use crate::config::Config;
+use crate::errors::Error::PluginNotInstalled;
+use crate::git::Git;
+use crate::output::Output;
+use crate::plugins::MANIFEST_FILENAME;
+
+/// Shows information about a plugin
+///
+/// This includes where it was installed from, the state of its caches,
+/// and the documentation from its help.* scripts if it has them
+#[derive(Debug, clap::Args)]
+#[clap(verbatim_doc_comment, after_long_help = AFTER_LONG_HELP.as_str())]
+pub struct PluginsInfo {
+    /// Plugin to show information about
+    #[clap()]
+    plugin: String,
+}
+
+impl Command for PluginsInfo {
+    fn run(self, config: Config, out: &mut Output) -> Result<()> {
+        let plugin = config
+            .plugins
+            .get(&self.plugin)
+            .ok_or(PluginNotInstalled(self.plugin))?;
+        let row = |out: &mut Output, label: &str, value: String| {
+            rtxprintln!(out, "{:<18}{value}", format!("{label}:"));
+        };
+        let git = Git::new(plugin.plugin_path.clone());
+        if git.is_repo() {
+            if let Some(url) = plugin.get_remote_url() {
+                row(out, "url", url);
+            }
+            let sha = git.current_sha_short()?;
+            row(out, "sha", style(sha).yellow().to_string());
+        }
+        if plugin.plugin_path.join(MANIFEST_FILENAME).exists() {
+            row(out, "manifest", MANIFEST_FILENAME.to_string());
+        }
+        for (cache, status) in plugin.cache_status() {
+            row(out, cache, format_cache_status(status));
+        }
+        for (topic, text) in plugin.help()? {
+            let title = match topic.as_str() {
+                "overview" => "Overview",
+                "deps" => "Dependencies",
+                "config" => "Configuration",
+                "links" => "Links",
+                _ => topic.as_str(),
+            };
+            rtxprintln!(out, "\n{}", style(title).bold());
+            for line in text.lines() {
+                rtxprintln!(out, "{}", format!("  {line}").trim_end());
+            }
+        }
+        Ok(())
+    }
+}
+
+fn format_cache_status(status: Option<(SystemTime, bool)>) -> String {
+    match status {
+        Some((modified, fresh)) => {
+            let modified = DateTime::<Local>::from(modified).format("%Y-%m-%d %H:%M");
+            match fresh {
+                true => modified.to_string(),
+                false => format!("{modified} {}", style("(stale)").dim()),
+            }
+        }
+        None => "not cached".into(),
+    }
+}
+
+static AFTER_LONG_HELP: Lazy<String> = Lazy::new(|| {
+    formatdoc! {r#"
+    {}
+      $ rtx plugins info nodejs
+      url:              https://github.com/asdf-vm/asdf-nodejs.git
+      sha:              1a2b3c4
+      remote versions:  2023-02-20 10:12
+      aliases:          2023-02-20 10:12
+      legacy filenames: 2023-02-20 10:12
+      latest stable:    not cached
+
+      Overview
+        ...
+    "#, style("Examples:").bold().underlined()}
+});
+
+#[cfg(test)]
+mod tests {
+    use crate::{assert_cli, assert_cli_err};
+
+    #[test]
+    fn test_plugin_info() {
+        assert_cli!("latest", "dummy");
+        let stdout = assert_cli!("plugins", "info", "dummy");
+        assert!(stdout.contains("remote versions:  "));
+        assert!(stdout.contains("latest stable:    "));
+        assert!(stdout.contains("Overview\n  Dummy plugin documentation\n\n  Dummy plugin is"));
+    }
+
+    #[test]
+    fn test_plugin_info_not_installed() {
+        let err = assert_cli_err!("plugins", "info", "invalid-plugin");
+        assert_eq!(err.to_string(), "[invalid-plugin] plugin not installed");
+    }
+}
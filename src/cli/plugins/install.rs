@@ -5,7 +5,9 @@ use once_cell::sync::Lazy;
 use url::Url;
 
 use crate::cli::command::Command;
+use crate::config::config_file::ConfigFile;
 use crate::config::Config;
+use crate::git::split_url_ref;
 use crate::output::Output;
 use crate::plugins::Plugin;
 use crate::toolset::ToolsetBuilder;
@@ -23,13 +25,15 @@ pub struct PluginsInstall {
     /// The name of the plugin to install
     ///
     /// e.g.: nodejs, ruby
-    #[clap(required_unless_present = "all")]
+    /// can also be `<name>@<ref>` to pin the plugin to a git branch, tag, or sha
+    #[clap(required_unless_present = "all", verbatim_doc_comment)]
     name: Option<String>,
 
     /// The git url of the plugin
     ///
     /// e.g.: https://github.com/asdf-vm/asdf-nodejs.git
-    #[clap(help = "The git url of the plugin", value_hint = clap::ValueHint::Url)]
+    /// append `#<ref>` to pin the plugin to a git branch, tag, or sha
+    #[clap(help = "The git url of the plugin", value_hint = clap::ValueHint::Url, verbatim_doc_comment)]
     git_url: Option<String>,
 
     /// Reinstall even if plugin exists
@@ -56,13 +60,20 @@ impl Command for PluginsInstall {
         let (name, git_url) = get_name_and_url(&config, self.name.unwrap(), self.git_url)?;
         let plugin = Plugin::new(&name);
         if self.force {
-            plugin.uninstall()?;
+            plugin.uninstall(&config.settings)?;
         }
         if !self.force && plugin.is_installed() {
             warn!("plugin {} already installed", name);
         } else {
             let pr = ProgressReport::new(config.settings.verbose);
             plugin.install(&config, Some(&git_url), pr)?;
+            // record the pin unless it came from a `[plugins]` entry already
+            if split_url_ref(&git_url).1.is_some()
+                && config.get_shorthands().get(&name) != Some(&git_url)
+            {
+                config.rtxrc.set_plugin_repo(&name, &git_url)?;
+                config.rtxrc.save()?;
+            }
         }
 
         Ok(())
@@ -99,11 +110,18 @@ fn get_name_and_url(
         None => match name.contains(':') {
             true => (get_name_from_url(&name)?, name),
             false => {
+                let (name, gitref) = match name.split_once('@') {
+                    Some((name, gitref)) => (name.to_string(), Some(gitref)),
+                    None => (name.clone(), None),
+                };
                 let git_url = config
                     .get_shorthands()
                     .get(&name)
                     .ok_or_else(|| eyre!("could not find plugin {}", name))?;
-                (name, git_url.to_string())
+                match gitref {
+                    Some(gitref) => (name, format!("{git_url}#{gitref}")),
+                    None => (name, git_url.to_string()),
+                }
             }
         },
     })
@@ -135,6 +153,11 @@ static AFTER_LONG_HELP: Lazy<String> = Lazy::new(|| {
       # install the nodejs plugin using the git url only
       # (nodejs is inferred from the url)
       $ rtx install https://github.com/asdf-vm/asdf-nodejs.git
+
+      # install the nodejs plugin pinned to a git tag, this is recorded in
+      # ~/.config/rtx/config.toml so `rtx plugins update` keeps it at v1.2.0
+      $ rtx plugins install nodejs@v1.2.0
+      $ rtx plugins install nodejs https://github.com/asdf-vm/asdf-nodejs.git#v1.2.0
     "#, style("Examples:").bold().underlined()}
 });
 
@@ -144,10 +167,23 @@ mod tests {
 
     use crate::cli::tests::cli_run;
 
+    use super::*;
+
     #[test]
     fn test_plugin_install_invalid_url() {
         let args = ["rtx", "plugin", "add", "tiny:"].map(String::from).into();
         let err = cli_run(&args).unwrap_err();
         assert_display_snapshot!(err);
     }
+
+    #[test]
+    fn test_get_name_and_url_with_ref() {
+        let config = Config::load().unwrap();
+        let (name, url) = get_name_and_url(&config, "nodejs@v1.2.0".into(), None).unwrap();
+        assert_eq!(name, "nodejs");
+        assert_eq!(url, "https://github.com/asdf-vm/asdf-nodejs.git#v1.2.0");
+        let url = "https://github.com/asdf-vm/asdf-nodejs.git#v1.2.0".to_string();
+        let (name, _) = get_name_and_url(&config, url, None).unwrap();
+        assert_eq!(name, "nodejs");
+    }
 }
@@ -5,9 +5,12 @@ use crate::cli::command::Command;
 use crate::config::Config;
 use crate::output::Output;
 
+mod history;
+mod info;
 mod install;
 mod ls;
 mod ls_remote;
+mod rollback;
 mod uninstall;
 mod update;
 
@@ -32,9 +35,12 @@ pub struct Plugins {
 
 #[derive(Debug, Subcommand)]
 enum Commands {
+    History(history::PluginsHistory),
+    Info(info::PluginsInfo),
     Install(install::PluginsInstall),
     Ls(ls::PluginsLs),
     LsRemote(ls_remote::PluginsLsRemote),
+    Rollback(rollback::PluginsRollback),
     Uninstall(uninstall::PluginsUninstall),
     Update(update::Update),
 }
@@ -42,9 +48,12 @@ enum Commands {
 impl Commands {
     pub fn run(self, config: Config, out: &mut Output) -> Result<()> {
         match self {
+            Self::History(cmd) => cmd.run(config, out),
+            Self::Info(cmd) => cmd.run(config, out),
             Self::Install(cmd) => cmd.run(config, out),
             Self::Ls(cmd) => cmd.run(config, out),
             Self::LsRemote(cmd) => cmd.run(config, out),
+            Self::Rollback(cmd) => cmd.run(config, out),
             Self::Uninstall(cmd) => cmd.run(config, out),
             Self::Update(cmd) => cmd.run(config, out),
         }
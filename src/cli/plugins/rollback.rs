@@ -0,0 +1,124 @@
+use color_eyre::eyre::Result;
+use console::style;
+use indoc::formatdoc;
+use once_cell::sync::Lazy;
+
+use crate::cli::command::Command;
+use crate::config::Config;
+use crate::errors::Error::PluginNotInstalled;
+use crate::git::short_sha;
+use crate::output::Output;
+
+/// Rolls back a plugin to a previous git sha
+///
+/// by default this checks out the sha the plugin was at before its last update,
+/// see `rtx plugins history` for the shas that can be rolled back to
+///
+/// note that `rtx plugins update` or autoupdate will update the plugin again,
+/// pin it with `rtx plugins install <PLUGIN>@<SHA>` to keep it at this sha
+#[derive(Debug, clap::Args)]
+#[clap(verbatim_doc_comment, after_long_help = AFTER_LONG_HELP.as_str())]
+pub struct PluginsRollback {
+    /// Plugin to roll back
+    #[clap()]
+    plugin: String,
+
+    /// The sha to roll back to, may be abbreviated
+    #[clap(long)]
+    to: Option<String>,
+}
+
+impl Command for PluginsRollback {
+    fn run(self, config: Config, out: &mut Output) -> Result<()> {
+        let plugin = config
+            .plugins
+            .get(&self.plugin)
+            .ok_or(PluginNotInstalled(self.plugin))?;
+        let (prev, post) = plugin.rollback(self.to.as_deref())?;
+        rtxprintln!(
+            out,
+            "rolled back {} {} -> {}",
+            style(&plugin.name).cyan(),
+            short_sha(&prev),
+            short_sha(&post)
+        );
+        if let Some(gitref) = config.settings.plugin_ref(&plugin.name) {
+            warn!(
+                "{} is pinned to {gitref} in [plugins], `rtx plugins update` will move it back to that ref",
+                plugin.name
+            );
+        }
+        Ok(())
+    }
+}
+
+static AFTER_LONG_HELP: Lazy<String> = Lazy::new(|| {
+    formatdoc! {r#"
+    {}
+      $ rtx plugins rollback nodejs              # undo the last update of nodejs
+      $ rtx plugins rollback nodejs --to 1a2b3c4 # check out a specific sha
+    "#, style("Examples:").bold().underlined()}
+});
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::os::unix::fs::PermissionsExt;
+    use std::path::Path;
+
+    use insta::assert_display_snapshot;
+    use pretty_assertions::assert_str_eq;
+
+    use crate::git::Git;
+    use crate::plugins::Plugin;
+    use crate::test::{run_git, CleanupPlugins};
+    use crate::{assert_cli, assert_cli_err, dirs};
+
+    /// commits a bin/list-all that prints `version`, returning the new sha
+    fn commit_version(origin: &Path, version: &str) -> String {
+        let list_all = origin.join("bin/list-all");
+        fs::create_dir_all(list_all.parent().unwrap()).unwrap();
+        fs::write(&list_all, format!("#!/usr/bin/env bash\necho {version}\n")).unwrap();
+        fs::set_permissions(&list_all, fs::Permissions::from_mode(0o755)).unwrap();
+        run_git(origin, &["add", "."]);
+        run_git(origin, &["commit", "-q", "-m", version]);
+        run_git(origin, &["rev-parse", "HEAD"])
+    }
+
+    #[test]
+    fn test_plugin_rollback() {
+        let _cleanup = CleanupPlugins(vec!["rollback-test"]);
+        let origin = tempfile::tempdir().unwrap();
+        run_git(origin.path(), &["init", "-q", "-b", "main"]);
+        let v1 = commit_version(origin.path(), "1.0.0");
+        let url = format!("file://{}", origin.path().display());
+        Git::new(dirs::PLUGINS.join("rollback-test"))
+            .clone(&url)
+            .unwrap();
+        let v2 = commit_version(origin.path(), "2.0.0");
+        assert_cli!("plugins", "update", "rollback-test");
+        let v3 = commit_version(origin.path(), "3.0.0");
+        assert_cli!("plugins", "update", "rollback-test");
+        assert_str_eq!(assert_cli!("ls-remote", "rollback-test"), "3.0.0\n");
+
+        // defaults to the previous sha and clears the cached remote versions
+        let stdout = assert_cli!("plugins", "rollback", "rollback-test");
+        assert_str_eq!(
+            stdout,
+            format!("rolled back rollback-test {} -> {}\n", &v3[..7], &v2[..7])
+        );
+        assert_str_eq!(assert_cli!("ls-remote", "rollback-test"), "2.0.0\n");
+
+        assert_cli!("plugins", "rollback", "rollback-test", "--to", &v1[..7]);
+        assert_str_eq!(assert_cli!("ls-remote", "rollback-test"), "1.0.0\n");
+        let history = Plugin::new(&"rollback-test".into()).history().unwrap();
+        let shas = history.into_iter().map(|e| e.sha).collect::<Vec<_>>();
+        assert_eq!(shas, vec![v1.clone(), v2.clone(), v3, v2, v1]);
+    }
+
+    #[test]
+    fn test_plugin_rollback_not_git() {
+        let err = assert_cli_err!("plugins", "rollback", "tiny");
+        assert_display_snapshot!(err, @"plugin tiny is not a git repository");
+    }
+}
@@ -22,7 +22,7 @@ impl Command for PluginsUninstall {
         match plugin {
             Some(plugin) if plugin.is_installed() => {
                 rtxprintln!(out, "uninstalling plugin: {}", style(&self.plugin).cyan());
-                plugin.uninstall()?;
+                plugin.uninstall(&config.settings)?;
             }
             _ => {
                 warn!(
@@ -4,15 +4,27 @@ use color_eyre::eyre::{eyre, Result};
 use console::style;
 use indoc::formatdoc;
 use once_cell::sync::Lazy;
+use rayon::prelude::*;
+use rayon::ThreadPoolBuilder;
 
 use crate::cli::command::Command;
+use crate::config::config_file::ConfigFile;
 use crate::config::Config;
+use crate::git::{short_sha, split_url_ref, Git};
 use crate::output::Output;
 use crate::plugins::Plugin;
+use crate::ui::multi_progress_report::MultiProgressReport;
+
+const MAX_SUMMARY_SUBJECTS: usize = 10;
 
 /// Updates a plugin to the latest version
 ///
 /// note: this updates the plugin itself, not the runtime versions
+///
+/// plugins are updated in parallel, to disable set `--jobs=1` or `RTX_JOBS=1`
+///
+/// plugins pinned to a git ref in the `[plugins]` section of ~/.config/rtx/config.toml
+/// are kept at that ref unless --unpin is passed
 #[derive(Debug, clap::Args)]
 #[clap(verbatim_doc_comment, alias = "upgrade", after_long_help = AFTER_LONG_HELP.as_str())]
 pub struct Update {
@@ -23,10 +35,15 @@ pub struct Update {
     /// Update all plugins
     #[clap(long, short = 'a', conflicts_with = "plugin")]
     all: bool,
+
+    /// Remove the pinned git ref and update to the default branch
+    #[clap(long)]
+    unpin: bool,
 }
 
 impl Command for Update {
     fn run(self, config: Config, out: &mut Output) -> Result<()> {
+        config.settings.ensure_online("update plugins")?;
         let plugins: Vec<&Arc<Plugin>> = match (self.plugin, self.all) {
             (Some(plugins), _) => plugins
                 .into_iter()
@@ -40,12 +57,91 @@ impl Command for Update {
             _ => Err(eyre!("no plugins specified"))?,
         };
 
-        for plugin in plugins {
-            rtxprintln!(out, "updating plugin {}", plugin.name);
-            plugin.update(None)?;
+        if self.unpin {
+            for plugin in &plugins {
+                unpin(&config, &plugin.name)?;
+            }
+        }
+        let settings = &config.settings;
+        let unpin = self.unpin;
+        let updates = ThreadPoolBuilder::new()
+            .num_threads(settings.jobs)
+            .build()
+            .unwrap()
+            .install(|| {
+                let mpr = MultiProgressReport::new(settings.verbose);
+                plugins
+                    .into_par_iter()
+                    .map(|plugin| {
+                        let gitref = match unpin {
+                            true => None,
+                            false => settings.plugin_ref(&plugin.name).map(String::from),
+                        };
+                        (plugin, plugin.update(settings, gitref, mpr.add()))
+                    })
+                    .collect::<Vec<_>>()
+            });
+
+        // one plugin failing should not hide what happened to the others
+        let mut failures = vec![];
+        for (plugin, update) in updates {
+            match update {
+                Ok(Some((prev, post))) if prev != post => print_summary(out, plugin, &prev, &post),
+                Ok(_) => {}
+                Err(err) => failures.push(format!("{}: {:#}", plugin.name, err)),
+            }
+        }
+        match failures.len() {
+            0 => Ok(()),
+            1 => Err(eyre!("failed to update plugin {}", failures[0])),
+            n => Err(eyre!(
+                "failed to update {n} plugins:\n{}",
+                failures.join("\n")
+            )),
+        }
+    }
+}
+
+/// prints the sha change of an updated plugin with the subjects of the new commits
+fn print_summary(out: &mut Output, plugin: &Plugin, prev: &str, post: &str) {
+    rtxprintln!(
+        out,
+        "{} {} -> {}",
+        style(&plugin.name).cyan(),
+        short_sha(prev),
+        short_sha(post)
+    );
+    let git = Git::new(plugin.plugin_path.clone());
+    let subjects = match git.log_subjects(prev, post) {
+        Ok(subjects) => subjects,
+        Err(err) => {
+            debug!("failed to get git log for {}: {:#}", plugin.name, err);
+            return;
+        }
+    };
+    for subject in subjects.iter().take(MAX_SUMMARY_SUBJECTS) {
+        rtxprintln!(out, "  {subject}");
+    }
+    if subjects.len() > MAX_SUMMARY_SUBJECTS {
+        let more = subjects.len() - MAX_SUMMARY_SUBJECTS;
+        rtxprintln!(out, "  {}", style(format!("...and {more} more")).dim());
+    }
+}
+
+/// removes the git ref from the plugin's entry in `[plugins]` of ~/.config/rtx/config.toml
+fn unpin(config: &Config, plugin_name: &str) -> Result<()> {
+    let global_settings = config.rtxrc.settings();
+    if let Some(repo) = global_settings.plugins.get(plugin_name) {
+        let (url, gitref) = split_url_ref(repo);
+        if gitref.is_some() {
+            config.rtxrc.set_plugin_repo(plugin_name, url)?;
+            config.rtxrc.save()?;
         }
-        Ok(())
     }
+    if global_settings.plugin_ref(plugin_name) != config.settings.plugin_ref(plugin_name) {
+        warn!("{plugin_name} is also pinned in a project config file, remove the ref there to unpin it");
+    }
+    Ok(())
 }
 
 static AFTER_LONG_HELP: Lazy<String> = Lazy::new(|| {
@@ -53,14 +149,20 @@ static AFTER_LONG_HELP: Lazy<String> = Lazy::new(|| {
     {}
       $ rtx plugins update --all   # update all plugins
       $ rtx plugins update nodejs  # update only nodejs
+      $ rtx plugins update --unpin nodejs  # update nodejs to the default branch if it was pinned
     "#, style("Examples:").bold().underlined()}
 });
 
 #[cfg(test)]
 mod tests {
+    use std::fs;
+
     use pretty_assertions::assert_str_eq;
 
-    use crate::{assert_cli, assert_cli_err};
+    use crate::test::{run_git, CleanupPlugins};
+    use crate::{assert_cli, assert_cli_err, dirs};
+
+    use super::*;
 
     #[test]
     fn test_plugin_update() {
@@ -74,4 +176,40 @@ mod tests {
         assert_str_eq!(err.to_string(), "no plugins specified");
         assert_cli!("plugins", "update", "tiny");
     }
+
+    #[test]
+    fn test_plugin_update_failures() {
+        let _cleanup = CleanupPlugins(vec!["update-ok", "update-broken-1", "update-broken-2"]);
+        let origin = tempfile::tempdir().unwrap();
+        run_git(origin.path(), &["init", "-q", "-b", "main"]);
+        run_git(origin.path(), &["commit", "-q", "--allow-empty", "-m", "1"]);
+        let url = format!("file://{}", origin.path().display());
+        let ok = dirs::PLUGINS.join("update-ok");
+        Git::new(ok.clone()).clone(&url).unwrap();
+        run_git(origin.path(), &["commit", "-q", "--allow-empty", "-m", "2"]);
+        // repos without an origin fail to update
+        let broken = ["update-broken-1", "update-broken-2"].map(|p| dirs::PLUGINS.join(p));
+        for path in &broken {
+            fs::create_dir_all(path).unwrap();
+            run_git(path, &["init", "-q"]);
+        }
+
+        let config = Config::load().unwrap();
+        let mut out = Output::tracked();
+        let update = Update {
+            plugin: Some(vec![
+                "update-broken-1".into(),
+                "update-ok".into(),
+                "update-broken-2".into(),
+            ]),
+            all: false,
+            unpin: false,
+        };
+        let err = update.run(config, &mut out).unwrap_err().to_string();
+        let stdout = console::strip_ansi_codes(&out.stdout.content).to_string();
+        assert!(stdout.starts_with("update-ok "));
+        assert!(stdout.ends_with("  2\n"));
+        assert!(err.starts_with("failed to update 2 plugins:\nupdate-broken-1: "));
+        assert!(err.contains("\nupdate-broken-2: "));
+    }
 }
@@ -0,0 +1,108 @@
+use color_eyre::eyre::Result;
+use console::style;
+use indicatif::HumanBytes;
+use indoc::formatdoc;
+use once_cell::sync::Lazy;
+
+use crate::cli::command::Command;
+use crate::config::{tracking, Config};
+use crate::output::Output;
+use crate::plugins::PluginName;
+use crate::toolset::ToolsetBuilder;
+
+/// Delete runtime versions that are no longer used
+///
+/// rtx keeps track of the config files it has loaded in ~/.local/share/rtx/tracked_config_files.
+/// Runtimes that are not referenced by any of those config files (or the current directory)
+/// are removed along with their download and cache directories.
+#[derive(Debug, clap::Args)]
+#[clap(verbatim_doc_comment, after_long_help = AFTER_LONG_HELP.as_str())]
+pub struct Prune {
+    /// Prune only versions from these plugins
+    #[clap()]
+    plugin: Vec<PluginName>,
+
+    /// List the versions that would be removed without removing them
+    #[clap(long)]
+    dry_run: bool,
+}
+
+impl Command for Prune {
+    fn run(self, config: Config, out: &mut Output) -> Result<()> {
+        let ts = ToolsetBuilder::new().without_resolve().build(&config);
+        let used = tracking::installed_versions_in_use(&config)?;
+
+        let mut count = 0;
+        let mut size = 0;
+        for rtv in ts.list_installed_versions()? {
+            if used.contains(&(rtv.plugin.name.clone(), rtv.version.clone()))
+                || (!self.plugin.is_empty() && !self.plugin.contains(&rtv.plugin.name))
+            {
+                continue;
+            }
+            let rtv_size = rtv.disk_usage();
+            rtxprintln!(out, "{} {}", rtv, style(HumanBytes(rtv_size)).dim());
+            if !self.dry_run {
+                rtv.uninstall(&config.settings)?;
+            }
+            count += 1;
+            size += rtv_size;
+        }
+
+        let plural = if count == 1 { "" } else { "s" };
+        match self.dry_run {
+            true => info!("would prune {count} version{plural} ({})", HumanBytes(size)),
+            false => info!("pruned {count} version{plural} ({})", HumanBytes(size)),
+        }
+        Ok(())
+    }
+}
+
+static AFTER_LONG_HELP: Lazy<String> = Lazy::new(|| {
+    formatdoc! {r#"
+    {}
+      $ rtx prune --dry-run
+      nodejs@18.0.0 45.12 MiB
+      $ rtx prune
+      nodejs@18.0.0 45.12 MiB
+      INFO  pruned 1 version (45.12 MiB)
+    "#, style("Examples:").bold().underlined()}
+});
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::os::unix::fs::PermissionsExt;
+
+    use crate::config::tracking;
+    use crate::test::CleanupPlugins;
+    use crate::{assert_cli, dirs, env};
+
+    #[test]
+    fn test_prune_dry_run() {
+        // fake installs of a separate plugin so the tiny installs other tests use are left alone
+        let _cleanup = CleanupPlugins(vec!["prune-test"]);
+        let list_all = dirs::PLUGINS.join("prune-test/bin/list-all");
+        fs::create_dir_all(list_all.parent().unwrap()).unwrap();
+        fs::write(&list_all, "#!/usr/bin/env bash\necho 1.0.0 1.0.1 2.0.0\n").unwrap();
+        fs::set_permissions(&list_all, fs::Permissions::from_mode(0o755)).unwrap();
+        for version in ["1.0.0", "2.0.0"] {
+            fs::create_dir_all(dirs::INSTALLS.join("prune-test").join(version)).unwrap();
+        }
+        let stdout = assert_cli!("prune", "--dry-run", "prune-test");
+        assert!(stdout.contains("prune-test@1.0.0"));
+        assert!(stdout.contains("prune-test@2.0.0"));
+
+        // older installed versions that still match a tracked config file are kept
+        // even though the remote has a newer match (1.0.1)
+        let dir = tempfile::tempdir().unwrap();
+        let config_path = dir
+            .path()
+            .join(env::RTX_DEFAULT_TOOL_VERSIONS_FILENAME.as_str());
+        fs::write(&config_path, "prune-test 1.0\n").unwrap();
+        tracking::track(&config_path).unwrap();
+        let stdout = assert_cli!("prune", "--dry-run", "prune-test");
+        assert!(!stdout.contains("prune-test@1.0.0"));
+        assert!(stdout.contains("prune-test@2.0.0"));
+    }
+}
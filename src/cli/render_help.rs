@@ -353,16 +353,80 @@ is:
 ```
 nodejs      18.0.0       # comments are allowed
 ruby        3            # can be fuzzy version
-shellcheck  latest       # also supports "latest"
+shellcheck  latest       # also supports "latest" and "latest-prerelease"
 jq          1.6
 erlang      ref:master   # compile from vcs ref
 golang      prefix:1.19  # uses the latest 1.19.x version—needed in case "1.19" is an exact match
+python      ~3.10.4      # semver ranges like ^1.2, ~3.10.4, and ">=18.12 <20" use the highest match
 shfmt       path:./shfmt # use a custom runtime
 ```
 
 Create `.tool-versions` files manually, or use [`rtx local`](#rtx-local) to create them automatically.
 See [the asdf docs](https://asdf-vm.com/manage/configuration.html#tool-versions) for more info on this file format.
 
+### `.rtx.toml`
+
+`.rtx.toml` is a project-local config file that can be used in place of (or alongside) `.tool-versions`.
+Like `.tool-versions`, rtx searches for it in the current directory and all parent directories.
+It can declare runtimes, environment variables, and any of the settings from
+[`~/.config/rtx/config.toml`](#global-config-configrtxconfigtoml):
+
+```toml
+jobs = 2                 # settings go at the top of the file
+
+nodejs = "18"            # same version syntax as .tool-versions
+python = ["3.11", "3.10"]
+golang = "prefix:1.19"
+
+[env]
+NODE_ENV = "production"  # exported by `rtx activate`, `rtx exec`, `rtx env`, and `rtx direnv`
+```
+
+If a directory has both a `.rtx.toml` and a `.tool-versions`, the runtimes in `.rtx.toml` take precedence.
+Settings and `[env]` vars in nested `.rtx.toml` files are merged with the closest file to the current
+directory winning. When leaving the directory, `rtx activate` will unset any env vars it added.
+
+Runtimes can also be declared as a table to set per-tool options:
+
+```toml
+nodejs = {{ version = "18", install_env = {{ NODE_CONFIGURE_OPTS = "--debug" }} }}
+python = {{ version = ["3.11", "3.10"], bin_paths = ["bin", "sbin"] }}
+shellcheck = {{ version = "0.9", optional = true }}
+```
+
+* `install_env` - extra env vars passed to the plugin's download/install scripts
+* `bin_paths` - directories (relative to the install path) to add to PATH instead of the plugin's `bin/list-bin-paths`
+* `optional` - do not warn, prompt, or auto-install if the runtime is missing
+
+Plugins can be declared in a `[plugins]` table to set the git repository they are installed from.
+These take precedence over the [shorthands](https://github.com/asdf-vm/asdf-plugins) so a project
+can use private or forked plugins which rtx installs automatically like any other missing plugin:
+
+```toml
+[plugins]
+nodejs = 'https://github.com/my-org/asdf-nodejs.git'  # append #<ref> to pin a branch, tag, or sha
+private = {{ url = 'git@github.com:my-org/asdf-private.git', ref = 'v1.2.0' }}
+```
+
+### Lockfiles
+
+Fuzzy versions like `18`, `prefix:18`, or `^18` resolve to the newest matching version, so they can
+install different versions over time. To prevent this, rtx can record the exact versions in a lockfile
+next to the config file (e.g.: `.tool-versions.lock` or `.rtx.toml.lock`) along with the git sha of each plugin:
+
+```toml
+[nodejs]
+plugin_sha = "8a5b4d0c4a0dc1a9b8b3d8f4a4d3f5a8e6d1c2b3"
+
+[nodejs.versions]
+18 = "18.14.0"
+```
+
+Lockfiles are written by `rtx install` and `rtx local` if they already exist or if the `lockfile`
+setting is enabled. When a lockfile exists, the locked versions are used instead of resolving again.
+Use `rtx install --frozen` in CI to fail if there is no lockfile, a runtime is missing from the lockfile,
+or a plugin is not at the locked sha.
+
 ### Legacy version files
 
 rtx supports "legacy version files" just like asdf. They're language-specific files like `.node-version`
@@ -423,9 +487,29 @@ jobs = 4            # number of plugins or runtimes to install in parallel. The
 shorthands_file = '~/.config/rtx/shorthands.toml' # path to the shorthands file, see `RTX_SHORTHANDS_FILE`
 disable_default_shorthands = false # disable the default shorthands, see `RTX_DISABLE_DEFAULT_SHORTHANDS`
 
+lockfile = false # write .tool-versions.lock files on install, see `RTX_LOCKFILE`
+offline = false  # never access the network, see `RTX_OFFLINE`
+prefer_installed = false # resolve fuzzy versions to installed versions when possible, see `RTX_PREFER_INSTALLED`
+
+# prereleases (e.g.: 1.0.0-rc1) are excluded from fuzzy versions, "latest", and `rtx ls-remote`
+# use "latest-prerelease" or `rtx ls-remote --prerelease` to include them for a single command
+include_prereleases = false
+prerelease_regex = '(-rc|-beta|-dev)' # overrides the pattern used to detect prereleases
+
+[plugin_settings.nodejs]
+include_prereleases = true            # these can also be set for a specific plugin
+default_packages_file = '~/.default-npm-packages' # passed to the postinstall hook, see "Hooks"
+
 [alias.nodejs]
 my_custom_node = '18'  # makes `rtx install nodejs@my_custom_node` install node-18.x
                        # this can also be specified in a plugin (see below in "Aliases")
+
+[hooks]
+post_asdf_install_nodejs = 'corepack enable'  # see below in "Hooks"
+
+[plugins]
+# pins the plugin to a git ref, `rtx plugins install nodejs@v1.2.0` adds this
+nodejs = 'https://github.com/asdf-vm/asdf-nodejs.git#v1.2.0'
 ```
 
 These settings can also be managed with `rtx settings ls|get|set|unset`.
@@ -460,6 +544,10 @@ This is the path to the config file. The default is `~/.config/rtx/config.toml`.
 
 Set to something other than ".tool-versions" to have rtx look for configuration with alternate names.
 
+#### `RTX_DEFAULT_CONFIG_FILENAME`
+
+Set to something other than ".rtx.toml" to have rtx look for `.rtx.toml` config files with a different name.
+
 #### `RTX_${{PLUGIN}}_VERSION`
 
 Set the version for a runtime. For example, `RTX_NODEJS_VERSION=18` will use nodejs@18.x regardless
@@ -519,6 +607,26 @@ Currently this disables the following:
 
 * `--fuzzy` as default behavior (`rtx local nodejs@18` will save exact version)
 
+#### `RTX_LOCKFILE=1`
+
+Write lockfiles next to config files when running `rtx install` or `rtx local`. See [Lockfiles](#lockfiles).
+
+#### `RTX_OFFLINE=1`
+
+Prevents rtx from accessing the network, e.g.: on a plane or in an air-gapped environment.
+Versions are resolved using only installed versions and previously cached remote versions (even if
+they are stale). Plugins and runtimes will not be installed automatically and rtx will not check for
+new versions of itself. Commands that need the network like `rtx plugins update`, `rtx ls-remote`,
+and `rtx latest` will fail with an error. This can also be set with `offline = true` in
+`~/.config/rtx/config.toml`.
+
+#### `RTX_PREFER_INSTALLED=1`
+
+Resolve fuzzy versions like `nodejs 18` to the highest installed version that matches instead of the
+newest remote version. Remote versions are only consulted if nothing installed matches. This avoids
+installing a new runtime every time a patch release comes out. Use `--prefer-installed` to enable
+this for a single command.
+
 #### `RTX_HIDE_OUTDATED_BUILD=1`
 
 If a release is 12 months old, it will show a warning message every time it launches:
@@ -569,6 +677,117 @@ See https://github.com/asdf-vm/asdf-plugins for the list of built-in plugins sho
 [Create a Plugin](https://asdf-vm.com/plugins/create.html) for how to create your own or just learn
 more about how they work.
 
+Plugins are updated in the background after they are used by `rtx install` or `rtx ls-remote`, at most
+once every `plugin_autoupdate_last_check_duration` minutes (one week by default). Plugins pinned to a
+git ref in `[plugins]` or whose sha is recorded in a [lockfile](#lockfiles) are not updated in the
+background. Set `plugin_autoupdate_last_check_duration = 0` to disable this and update plugins manually
+with `rtx plugins update`.
+
+rtx keeps track of the git shas each plugin has been at. If an update breaks something, run
+`rtx plugins rollback <PLUGIN>` to go back to the previous sha or `rtx plugins history <PLUGIN>` to
+see the shas that can be passed to `rtx plugins rollback <PLUGIN> --to <SHA>`.
+
+### Hooks
+
+rtx supports asdf's [hooks](https://asdf-vm.com/manage/configuration.html#plugin-hooks). They are
+defined in the `[hooks]` section of `~/.config/rtx/config.toml` and run with bash:
+
+```toml
+[hooks]
+pre_asdf_install = 'echo "installing $1"'   # runs before installing any runtime, $1 is the version
+post_asdf_install_nodejs = 'corepack enable' # only runs after installing a nodejs version
+post_asdf_plugin_add = 'echo "added $1"'    # $1 is the plugin name
+```
+
+The following hooks are available, each can be suffixed with `_<PLUGIN>` to only run for that plugin:
+
+- `pre_asdf_install`/`post_asdf_install`: `$1` is the version
+- `pre_asdf_uninstall`/`post_asdf_uninstall`: `$1` is the version
+- `pre_asdf_plugin_add`/`post_asdf_plugin_add`: `$1` is the plugin name
+- `pre_asdf_plugin_update`/`post_asdf_plugin_update`: `$1` is the plugin name, the `post_` hook also
+  gets the previous and new git refs as `$2` and `$3`
+- `pre_asdf_plugin_remove`/`post_asdf_plugin_remove`: `$1` is the plugin name
+
+If a hook exits with a non-zero status, the command that triggered it fails. Since hooks run arbitrary
+commands, `[hooks]` in a project's `.rtx.toml` are ignored with a warning.
+
+rtx also has a `postinstall` hook which runs after a runtime is installed with its bin paths on PATH
+and its `bin/exec-env` vars set. This makes it useful for installing default global packages. The
+packages listed in `default_packages_file` (one per line, `#` starts a comment) are passed as arguments:
+
+```toml
+[hooks]
+postinstall_nodejs = 'npm install -g "$@"'
+
+[plugin_settings.nodejs]
+default_packages_file = '~/.default-npm-packages'
+```
+
+If a `postinstall` hook fails, the runtime is removed and the install fails. Like the other hooks,
+`postinstall` and `default_packages_file` can only be set in `~/.config/rtx/config.toml`.
+
+Plugins can also provide `bin/post-plugin-add`, `bin/post-plugin-update`, and `bin/pre-plugin-remove`
+scripts which rtx runs in the same way as asdf.
+
+If a plugin has a `bin/latest-stable` script, rtx uses it to resolve `latest` and prefixes like `18`
+(passed as its argument) instead of filtering prereleases out of `bin/list-all`, unless
+`include_prereleases` or `prerelease_regex` are set for the plugin. Exact versions don't use it, and
+output that doesn't match the query (e.g.: `20.19.0` for `20.1`) is ignored. Its output is cached for
+each query like the remote versions. The `bin/help.overview`, `bin/help.deps`,
+`bin/help.config`, and `bin/help.links` scripts are shown by `rtx plugins info <PLUGIN>` along with
+where the plugin was installed from and when its caches were last updated.
+
+Plugins where the executables in the bin paths are wrappers can provide `bin/exec-path`. It gets the
+install path, the command, and where rtx found the command relative to the install path, and prints
+the path to run instead, or nothing to run the command that was found. This is used by `rtx exec`, `rtx which`, and shims, but not for commands run
+with `rtx exec --command` or through PATH.
+
+### Plugin manifest
+
+Plugins can include an `rtx-plugin.toml` file to declare what some of their scripts would output. rtx
+reads it directly instead of running the scripts, which saves spawning a shell for every plugin when
+rtx loads its config:
+
+```toml
+legacy_filenames = ['.nvmrc', '.node-version']  # instead of bin/list-legacy-filenames
+bin_paths = ['bin']                             # instead of bin/list-bin-paths
+prerelease_regex = '-(rc|nightly)'              # instead of rtx's default prerelease regex
+
+[aliases]                                       # instead of bin/list-aliases
+lts = '18'
+
+[exec_env]                                      # instead of bin/exec-env
+NODE_HOME = '$ASDF_INSTALL_PATH'                # $ASDF_* vars are expanded
+```
+
+Every key is optional, scripts are still used for anything that isn't declared. The `prerelease_regex`
+setting and the `bin_paths` tool option in rtx's own config take precedence over the manifest.
+
+### WebAssembly plugins
+
+_This is experimental and requires building rtx with `cargo install rtx-cli --features wasm`, which
+needs rust 1.82 or newer._
+
+Instead of shell scripts, a plugin can be a [WASI](https://wasi.dev) module named `plugin.wasm` at the
+root of the plugin repository. The module exports a function for each of the scripts an asdf plugin
+would have (`list-all`, `list-aliases`, `list-legacy-filenames`, `parse-legacy-file`, `download`,
+`install`, `uninstall`, `list-bin-paths`, `exec-env`, `exec-path`, `latest-stable`, and the `help.*`
+scripts) which print their output to stdout like the scripts do. Only `list-all` and `install` are
+required.
+
+Each function runs in a sandbox that only has access to what that operation needs:
+
+- The `ASDF_*` env vars and the tool's `install_env`, nothing else from the environment.
+- `install` can read `ASDF_DOWNLOAD_PATH` and write to `ASDF_INSTALL_PATH`, `uninstall` can write to
+  `ASDF_INSTALL_PATH`, and `list-bin-paths`, `exec-env`, and `exec-path` can read it. Nothing else has
+  filesystem access.
+- `parse-legacy-file` gets the contents of the file on stdin.
+- There is no network access and no way to run other programs. Instead, `download` prints
+  `<url> [filename]` lines and rtx downloads each of them into `ASDF_DOWNLOAD_PATH`.
+- `exec-env` prints `KEY=VALUE` lines rather than being sourced.
+
+The plugin lifecycle scripts (`post-plugin-add`, etc.) are not supported for WebAssembly plugins.
+
 ## FAQs
 
 ### I don't want to put a `.tool-versions` file into my project since git shows it as an untracked file.
@@ -15,7 +15,8 @@ use crate::output::Output;
 pub struct SelfUpdate {}
 
 impl Command for SelfUpdate {
-    fn run(self, _config: Config, out: &mut Output) -> Result<()> {
+    fn run(self, config: Config, out: &mut Output) -> Result<()> {
+        config.settings.ensure_online("update rtx")?;
         let current_version =
             env::var("RTX_SELF_UPDATE_VERSION").unwrap_or(cargo_crate_version!().to_string());
         let mut update = Update::configure();
@@ -34,6 +34,11 @@ impl Command for SettingsSet {
             "shorthands_file" => self.value.into(),
             "disable_default_shorthands" => parse_bool(&self.value)?,
             "shims_dir" => self.value.into(),
+            "lockfile" => parse_bool(&self.value)?,
+            "offline" => parse_bool(&self.value)?,
+            "prefer_installed" => parse_bool(&self.value)?,
+            "include_prereleases" => parse_bool(&self.value)?,
+            "prerelease_regex" => self.value.into(),
             _ => return Err(eyre!("Unknown setting: {}", self.key)),
         };
 
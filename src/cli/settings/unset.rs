@@ -59,6 +59,10 @@ mod tests {
         disable_default_shorthands = false
         log_level = INFO
         shims_dir = ~/data/shims
+        lockfile = false
+        offline = false
+        prefer_installed = false
+        include_prereleases = false
         "###);
 
         reset_config();
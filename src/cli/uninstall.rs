@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use color_eyre::eyre::{eyre, Result, WrapErr};
 use console::style;
 use indoc::formatdoc;
@@ -6,42 +8,113 @@ use once_cell::sync::Lazy;
 use crate::cli::args::runtime::{RuntimeArg, RuntimeArgParser};
 use crate::cli::command::Command;
 use crate::config::Config;
+use crate::duration::parse_duration;
 use crate::output::Output;
-use crate::toolset::ToolsetBuilder;
+use crate::runtimes::RuntimeVersion;
+use crate::toolset::{Toolset, ToolsetBuilder};
 
 /// Removes runtime versions
 #[derive(Debug, clap::Args)]
 #[clap(verbatim_doc_comment, alias = "remove", alias = "rm", after_long_help = AFTER_LONG_HELP.as_str())]
 pub struct Uninstall {
     /// Runtime(s) to remove
-    #[clap(required = true, value_parser = RuntimeArgParser)]
+    #[clap(required_unless_present = "unused_for", value_parser = RuntimeArgParser)]
     runtime: Vec<RuntimeArg>,
+
+    /// Remove all versions that have not been used for this long, e.g.: 90d, 2w, 12h
+    ///
+    /// Versions that have never been used are removed if they were installed before then.
+    /// If runtimes are specified, only versions of those plugins are removed.
+    #[clap(long, value_parser = |s: &str| parse_duration(s).map_err(|e| e.to_string()), verbatim_doc_comment)]
+    unused_for: Option<Duration>,
 }
 
 impl Command for Uninstall {
     fn run(self, config: Config, out: &mut Output) -> Result<()> {
         let runtimes = RuntimeArg::double_runtime_condition(&self.runtime);
         let ts = ToolsetBuilder::new().with_args(&runtimes).build(&config);
-        let runtime_versions = runtimes.iter().filter_map(|a| ts.resolve_runtime_arg(a));
+        let runtime_versions = match self.unused_for {
+            Some(unused_for) => self.unused_versions(&ts, unused_for)?,
+            None => runtimes
+                .iter()
+                .filter_map(|a| ts.resolve_runtime_arg(a))
+                .cloned()
+                .collect(),
+        };
 
-        for rtv in runtime_versions {
+        for rtv in &runtime_versions {
             if !rtv.is_installed() {
                 warn!("{} is not installed", style(rtv).cyan().for_stderr());
                 continue;
             }
 
             rtxprintln!(out, "uninstalling {}", style(rtv).cyan());
-            rtv.uninstall()
+            rtv.uninstall(&config.settings)
                 .wrap_err_with(|| eyre!("error uninstalling {}", rtv))?;
         }
         Ok(())
     }
 }
 
+impl Uninstall {
+    fn unused_versions(&self, ts: &Toolset, unused_for: Duration) -> Result<Vec<RuntimeVersion>> {
+        let versions = ts
+            .list_installed_versions()?
+            .into_iter()
+            .filter(|rtv| {
+                self.runtime.is_empty() || self.runtime.iter().any(|r| r.plugin == rtv.plugin.name)
+            })
+            .filter(|rtv| match rtv.last_used().or_else(|| rtv.installed_at()) {
+                Some(time) => time.elapsed().unwrap_or_default() > unused_for,
+                None => false,
+            })
+            .collect();
+        Ok(versions)
+    }
+}
+
 static AFTER_LONG_HELP: Lazy<String> = Lazy::new(|| {
     formatdoc! {r#"
     {}
       $ rtx uninstall nodejs@18.0.0 # will uninstall specific version
       $ rtx uninstall nodejs        # will uninstall current nodejs version
+      $ rtx uninstall --unused-for 90d  # will uninstall versions not used in 90 days
     "#, style("Examples:").underlined().bold()}
 });
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::os::unix::fs::PermissionsExt;
+
+    use filetime::{set_file_mtime, FileTime};
+
+    use crate::test::CleanupPlugins;
+    use crate::{assert_cli, dirs};
+
+    #[test]
+    fn test_uninstall_unused_for() {
+        // fake installs of a separate plugin so the tiny installs other tests use are left alone
+        let _cleanup = CleanupPlugins(vec!["unused-test"]);
+        let list_all = dirs::PLUGINS.join("unused-test/bin/list-all");
+        fs::create_dir_all(list_all.parent().unwrap()).unwrap();
+        fs::write(&list_all, "#!/usr/bin/env bash\necho 1.0.0 2.0.0 3.0.0\n").unwrap();
+        fs::set_permissions(&list_all, fs::Permissions::from_mode(0o755)).unwrap();
+        let install_path = |v| dirs::INSTALLS.join("unused-test").join(v);
+        for version in ["1.0.0", "2.0.0", "3.0.0"] {
+            fs::create_dir_all(install_path(version)).unwrap();
+        }
+        // never used and installed long ago
+        set_file_mtime(install_path("1.0.0"), FileTime::from_unix_time(0, 0)).unwrap();
+        // installed long ago but used recently
+        set_file_mtime(install_path("2.0.0"), FileTime::from_unix_time(0, 0)).unwrap();
+        let last_used = dirs::CACHE.join("unused-test/2.0.0/last_used");
+        fs::create_dir_all(last_used.parent().unwrap()).unwrap();
+        fs::write(&last_used, "").unwrap();
+
+        assert_cli!("uninstall", "--unused-for", "3650d", "unused-test");
+        assert!(!install_path("1.0.0").exists());
+        assert!(install_path("2.0.0").exists());
+        assert!(install_path("3.0.0").exists());
+    }
+}
@@ -0,0 +1,191 @@
+use color_eyre::eyre::Result;
+use console::style;
+use dialoguer::theme::ColorfulTheme;
+use dialoguer::MultiSelect;
+use indoc::formatdoc;
+use itertools::Itertools;
+use once_cell::sync::Lazy;
+
+use crate::cli::command::Command;
+use crate::cli::outdated::is_newer;
+use crate::config::{config_file, tracking, Config};
+use crate::lockfile;
+use crate::output::Output;
+use crate::plugins::PluginName;
+use crate::runtimes::RuntimeVersion;
+use crate::toolset::{ToolSource, ToolVersion, ToolVersionType, ToolsetBuilder};
+use crate::ui::progress_report::ProgressReport;
+
+/// Upgrades runtimes to the newest version matching what was requested
+///
+/// e.g.: with `nodejs 18` in .tool-versions this installs the latest nodejs 18.x
+///
+/// Runtimes pinned to an exact version are left alone unless `--bump` is passed,
+/// which upgrades them to the latest version and rewrites the config file.
+/// Afterwards rtx offers to uninstall the versions that were replaced
+/// if none of the config files rtx has seen (see `rtx prune`) still use them.
+#[derive(Debug, clap::Args)]
+#[clap(verbatim_doc_comment, after_long_help = AFTER_LONG_HELP.as_str())]
+pub struct Upgrade {
+    /// Plugin(s) to upgrade, if not specified all active runtimes are upgraded
+    ///
+    /// e.g.: nodejs, python
+    #[clap(verbatim_doc_comment)]
+    plugin: Vec<PluginName>,
+
+    /// Also upgrade pinned versions and rewrite them in the config file
+    #[clap(long)]
+    bump: bool,
+
+    /// Show what would be upgraded without installing anything
+    #[clap(long)]
+    dry_run: bool,
+}
+
+impl Command for Upgrade {
+    fn run(self, config: Config, out: &mut Output) -> Result<()> {
+        let mut ts = ToolsetBuilder::new().build(&config);
+        let mut replaced = vec![];
+        for (plugin_name, tvl) in ts.versions.iter_mut() {
+            if !self.plugin.is_empty() && !self.plugin.contains(plugin_name) {
+                continue;
+            }
+            for tv in tvl.versions.iter_mut() {
+                let rtv = match &tv.rtv {
+                    Some(rtv) => rtv.clone(),
+                    None => continue,
+                };
+                let pinned = matches!(&tv.r#type, ToolVersionType::Version(v) if v == &rtv.version);
+                let latest = match pinned {
+                    true if self.bump => rtv.plugin.latest_version(&config.settings, "latest")?,
+                    true => None,
+                    false => tv.latest_version(&config.settings, rtv.plugin.clone())?,
+                };
+                let latest = match latest {
+                    Some(latest) if is_newer(&latest, &rtv.version) => latest,
+                    _ => continue,
+                };
+                rtxprintln!(out, "{} {} -> {}", plugin_name, rtv.version, latest);
+                if self.dry_run {
+                    continue;
+                }
+                let mut new_tv = ToolVersion::new(
+                    plugin_name.clone(),
+                    ToolVersionType::Version(latest.clone()),
+                )
+                .with_options(tv.options.clone());
+                new_tv.resolve(&config.settings, rtv.plugin.clone())?;
+                if new_tv.is_missing() {
+                    new_tv.install(&config, ProgressReport::new(config.settings.verbose))?;
+                }
+                if pinned {
+                    bump_config(&tvl.source, plugin_name, &rtv.version, &latest)?;
+                    tv.r#type = new_tv.r#type;
+                }
+                tv.locked = None;
+                tv.rtv = new_tv.rtv;
+                replaced.push(rtv);
+            }
+        }
+        if self.dry_run {
+            return Ok(());
+        }
+        lockfile::update(&config, &ts)?;
+
+        let current = ts.list_current_versions();
+        let in_use = tracking::installed_versions_in_use(&config)?;
+        let unused = replaced
+            .into_iter()
+            .filter(|rtv| rtv.is_installed() && !current.contains(&rtv))
+            .filter(|rtv| !in_use.contains(&(rtv.plugin.name.clone(), rtv.version.clone())))
+            .unique_by(|rtv| rtv.to_string())
+            .collect_vec();
+        for rtv in prompt_for_uninstall(&unused)? {
+            info!("uninstalling {}", rtv);
+            rtv.uninstall(&config.settings)?;
+        }
+        Ok(())
+    }
+}
+
+/// replaces the pinned version in the config file it came from
+fn bump_config(source: &ToolSource, plugin: &PluginName, old: &str, new: &str) -> Result<()> {
+    let path = match source {
+        ToolSource::ToolVersions(path) | ToolSource::RtxRc(path) => path,
+        _ => return Ok(()),
+    };
+    let mut cf = config_file::parse(path)?;
+    let versions = cf
+        .plugins()
+        .get(plugin)
+        .cloned()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|v| if v == old { new.to_string() } else { v })
+        .collect_vec();
+    cf.replace_versions(plugin, &versions);
+    cf.save()
+}
+
+fn prompt_for_uninstall(versions: &[RuntimeVersion]) -> Result<Vec<&RuntimeVersion>> {
+    if versions.is_empty() {
+        return Ok(vec![]);
+    }
+    if !console::user_attended_stderr() {
+        for rtv in versions {
+            info!("{rtv} is no longer used, run `rtx uninstall {rtv}` to remove it");
+        }
+        return Ok(vec![]);
+    }
+    Ok(MultiSelect::with_theme(&ColorfulTheme::default())
+        .with_prompt("Select replaced versions to uninstall")
+        .items(versions)
+        .interact()?
+        .into_iter()
+        .map(|i| &versions[i])
+        .collect())
+}
+
+static AFTER_LONG_HELP: Lazy<String> = Lazy::new(|| {
+    formatdoc! {r#"
+    {}
+      $ rtx upgrade                  # upgrade all active runtimes
+      nodejs 18.0.0 -> 18.14.0
+      $ rtx upgrade nodejs --bump    # also upgrade nodejs if it is pinned to an exact version
+      $ rtx upgrade --dry-run        # show what would be upgraded
+    "#, style("Examples:").bold().underlined()}
+});
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use pretty_assertions::assert_str_eq;
+
+    use crate::{assert_cli, dirs};
+
+    /// restores the config file even if the test fails so other tests don't see its versions
+    struct RestoreFile(PathBuf, String);
+
+    impl Drop for RestoreFile {
+        fn drop(&mut self) {
+            fs::write(&self.0, &self.1).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_upgrade() {
+        let cf_path = dirs::CURRENT.join(".test-tool-versions");
+        let _restore = RestoreFile(cf_path.clone(), fs::read_to_string(&cf_path).unwrap());
+
+        fs::write(&cf_path, "tiny 3.0.1\n").unwrap();
+        let stdout = assert_cli!("upgrade", "tiny");
+        assert_str_eq!(stdout, "");
+        let stdout = assert_cli!("upgrade", "tiny", "--bump", "--dry-run");
+        assert_str_eq!(stdout, "tiny 3.0.1 -> 3.1.0\n");
+        assert_str_eq!(fs::read_to_string(&cf_path).unwrap(), "tiny 3.0.1\n");
+        assert_cli!("upgrade", "tiny", "--bump");
+        assert_str_eq!(fs::read_to_string(&cf_path).unwrap(), "tiny 3.1.0\n");
+    }
+}
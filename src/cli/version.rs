@@ -8,7 +8,8 @@ use versions::Versioning;
 
 use crate::build_time::BUILD_TIME;
 use crate::cli::command::Command;
-use crate::config::Config;
+use crate::config;
+use crate::config::{Config, Settings};
 use crate::output::Output;
 
 #[derive(Debug, clap::Args)]
@@ -40,8 +41,8 @@ pub static VERSION: Lazy<String> = Lazy::new(|| {
 });
 
 impl Command for Version {
-    fn run(self, _config: Config, out: &mut Output) -> Result<()> {
-        show_version(out);
+    fn run(self, config: Config, out: &mut Output) -> Result<()> {
+        show_version(out, &config.settings);
         Ok(())
     }
 }
@@ -50,19 +51,19 @@ pub fn print_version_if_requested(args: &[String], out: &mut Output) {
     if args.len() == 2 && (args[0] == "rtx" || args[0].ends_with("/rtx")) {
         let cmd = &args[1].to_lowercase();
         if cmd == "version" || cmd == "-v" || cmd == "--version" {
-            show_version(out);
+            show_version(out, &config::load_global_settings());
             std::process::exit(0);
         }
     }
 }
 
-fn show_version(out: &mut Output) {
+fn show_version(out: &mut Output, settings: &Settings) {
     rtxprintln!(out, "{}", *VERSION);
-    show_latest();
+    show_latest(settings);
 }
 
-fn show_latest() {
-    if let Some(latest) = check_for_new_version() {
+fn show_latest(settings: &Settings) {
+    if let Some(latest) = check_for_new_version(settings) {
         warn!("rtx version {} available", latest);
         if cfg!(feature = "self_update") {
             let cmd = style("rtx self-update").bright().yellow().for_stderr();
@@ -71,7 +72,10 @@ fn show_latest() {
     }
 }
 
-pub fn check_for_new_version() -> Option<String> {
+pub fn check_for_new_version(settings: &Settings) -> Option<String> {
+    if settings.offline {
+        return None;
+    }
     if let Some(latest) = get_latest_version() {
         let current = Versioning::new(env!("CARGO_PKG_VERSION")).unwrap();
         if current < latest {
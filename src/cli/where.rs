@@ -65,10 +65,14 @@ static AFTER_LONG_HELP: Lazy<String> = Lazy::new(|| {
 
 #[cfg(test)]
 mod tests {
+    use std::fs;
+    use std::os::unix::fs::PermissionsExt;
+
     use insta::assert_display_snapshot;
     use pretty_assertions::assert_str_eq;
 
     use crate::dirs;
+    use crate::test::CleanupPlugins;
     use crate::{assert_cli, assert_cli_err};
 
     #[test]
@@ -92,6 +96,21 @@ mod tests {
         assert_cli!("uninstall", "tiny@my/alias");
     }
 
+    #[test]
+    fn test_where_prefer_installed() {
+        // a separate plugin so the tiny installs other tests rely on are left alone
+        let _cleanup = CleanupPlugins(vec!["prefer-installed-test"]);
+        let list_all = dirs::PLUGINS.join("prefer-installed-test/bin/list-all");
+        fs::create_dir_all(list_all.parent().unwrap()).unwrap();
+        fs::write(&list_all, "#!/usr/bin/env bash\necho 2.0.0 2.0.1\n").unwrap();
+        fs::set_permissions(&list_all, fs::Permissions::from_mode(0o755)).unwrap();
+        let install_path = dirs::INSTALLS.join("prefer-installed-test/2.0.0");
+        fs::create_dir_all(&install_path).unwrap();
+
+        let stdout = assert_cli!("where", "--prefer-installed", "prefer-installed-test@2.0");
+        assert_str_eq!(stdout.trim(), install_path.to_string_lossy());
+    }
+
     #[test]
     fn test_where_not_found() {
         let err = assert_cli_err!("where", "tiny@1111");
@@ -1,7 +1,12 @@
 use std::ffi::OsString;
+use std::io::{BufRead, BufReader};
+use std::sync::Arc;
 
+use color_eyre::eyre::Result;
 use duct::{Expression, IntoExecutablePath};
 
+use crate::errors::Error::ScriptFailed;
+
 /// Create a command with any number of of positional arguments, which may be
 /// different types (anything that implements
 /// [`Into<OsString>`](https://doc.rust-lang.org/std/convert/trait.From.html)).
@@ -70,6 +75,43 @@ where
     duct::cmd(program, args)
 }
 
+/// runs a plugin's command (e.g.: its install script or a postinstall hook),
+/// calling `on_output` with each line of output
+pub fn run_by_line<F1, F2>(
+    plugin_name: &str,
+    cmd: Expression,
+    on_error: F1,
+    on_output: F2,
+) -> Result<()>
+where
+    F1: Fn(String),
+    F2: Fn(&str),
+{
+    let reader = cmd.stderr_to_stdout().unchecked().reader()?;
+    let reader = Arc::new(reader);
+    let mut output = vec![];
+    for line in BufReader::new(&*reader).lines() {
+        let line = line.unwrap();
+        on_output(&line);
+        output.push(line);
+    }
+
+    match reader.try_wait() {
+        Err(err) => {
+            on_error(output.join("\n"));
+            Err(err)?
+        }
+        Ok(out) => match out.unwrap().status.success() {
+            true => Ok(()),
+            false => {
+                on_error(output.join("\n"));
+                let err = ScriptFailed(plugin_name.to_string(), Some(out.unwrap().status));
+                Err(err)?
+            }
+        },
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::cmd;
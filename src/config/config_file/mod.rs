@@ -110,13 +110,11 @@ impl dyn ConfigFile {
 }
 
 pub fn init(path: &Path) -> Box<dyn ConfigFile> {
-    if path.ends_with(".rtxrc") || path.ends_with(".rtxrc.toml") {
-        return Box::new(RTXFile::init(path));
-    } else if path.ends_with(env::RTX_DEFAULT_TOOL_VERSIONS_FILENAME.as_str()) {
-        return Box::new(ToolVersions::init(path));
+    match detect_config_file_type(path) {
+        Some(ConfigFileType::RtxRc) => Box::new(RTXFile::init(path)),
+        Some(ConfigFileType::ToolVersions) => Box::new(ToolVersions::init(path)),
+        _ => panic!("Unknown config file type: {}", path.display()),
     }
-
-    panic!("Unknown config file type: {}", path.display());
 }
 
 pub fn parse(path: &Path) -> Result<Box<dyn ConfigFile>> {
@@ -128,9 +126,10 @@ pub fn parse(path: &Path) -> Result<Box<dyn ConfigFile>> {
     }
 }
 
-fn detect_config_file_type(path: &Path) -> Option<ConfigFileType> {
+pub fn detect_config_file_type(path: &Path) -> Option<ConfigFileType> {
     match path.file_name().unwrap().to_str().unwrap() {
         ".rtxrc" | ".rtxrc.toml" | "config.toml" => Some(ConfigFileType::RtxRc),
+        f if env::RTX_DEFAULT_CONFIG_FILENAME.as_str() == f => Some(ConfigFileType::RtxRc),
         f if env::RTX_DEFAULT_TOOL_VERSIONS_FILENAME.as_str() == f => {
             Some(ConfigFileType::ToolVersions)
         }
@@ -152,6 +151,10 @@ mod tests {
             detect_config_file_type(Path::new("/foo/bar/.rtxrc.toml")),
             Some(ConfigFileType::RtxRc)
         );
+        assert_eq!(
+            detect_config_file_type(Path::new("/foo/bar/.test.rtx.toml")),
+            Some(ConfigFileType::RtxRc)
+        );
         assert_eq!(
             detect_config_file_type(Path::new("/foo/bar/.test-tool-versions")),
             Some(ConfigFileType::ToolVersions)
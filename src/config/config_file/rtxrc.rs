@@ -8,15 +8,16 @@ use std::time::Duration;
 use color_eyre::eyre::{eyre, Context};
 use color_eyre::{Result, Section, SectionExt};
 use indexmap::IndexMap;
+use itertools::Itertools;
 use log::LevelFilter;
 use once_cell::sync::OnceCell;
 use toml::Value;
 
 use crate::config::config_file::{ConfigFile, ConfigFileType};
-use crate::config::settings::{MissingRuntimeBehavior, Settings, SettingsBuilder};
+use crate::config::settings::{MissingRuntimeBehavior, PluginSettings, Settings, SettingsBuilder};
 use crate::config::AliasMap;
 use crate::plugins::PluginName;
-use crate::toolset::Toolset;
+use crate::toolset::{ToolSource, ToolVersion, ToolVersionOptions, ToolVersionType, Toolset};
 
 const ENV_SUGGESTION: &str = r#"
 [env]
@@ -32,10 +33,12 @@ pub struct RTXFile {
     settings: SettingsBuilder,
 }
 
-#[derive(Debug, PartialEq, Eq, Hash, Default)]
+#[derive(Debug, PartialEq, Eq, Default)]
 pub struct Plugin {
     pub name: String,
     pub versions: Vec<String>,
+    /// options for each version, versions without options are not in here
+    pub options: IndexMap<String, ToolVersionOptions>,
 }
 
 impl RTXFile {
@@ -79,6 +82,10 @@ impl RTXFile {
         self.settings.build()
     }
 
+    pub fn settings_builder(&self) -> &SettingsBuilder {
+        &self.settings
+    }
+
     fn parse_toplevel_key(&mut self, k: &String, v: &Value) -> Result<()> {
         match k.to_lowercase().as_str() {
             "env" => self.parse_env(v).with_suggestion(|| ENV_SUGGESTION)?,
@@ -105,7 +112,19 @@ impl RTXFile {
             }
             "log_level" => self.settings.log_level = Some(self.parse_log_level(v)?),
             "shims_dir" => self.settings.shims_dir = Some(self.parse_path(k, v)?),
+            "lockfile" => self.settings.lockfile = Some(self.parse_bool(k, v)?),
+            "offline" => self.settings.offline = Some(self.parse_bool(k, v)?),
+            "prefer_installed" => self.settings.prefer_installed = Some(self.parse_bool(k, v)?),
+            "include_prereleases" => {
+                self.settings.include_prereleases = Some(self.parse_bool(k, v)?)
+            }
+            "prerelease_regex" => self.settings.prerelease_regex = Some(self.parse_string(k, v)?),
+            "plugin_settings" => {
+                self.settings.plugin_settings = Some(self.parse_plugin_settings(v)?)
+            }
             "alias" => self.settings.aliases = Some(self.parse_aliases(v)?),
+            "hooks" => self.settings.hooks = Some(self.parse_string_table(k, v)?),
+            "plugins" => self.settings.plugins = Some(self.parse_plugin_repos(v)?),
             "get_path" => {}
             "disable_plugin_short_name_repository" => {}
             "plugin_repository_last_check_duration" => {}
@@ -132,17 +151,71 @@ impl RTXFile {
     }
 
     fn parse_plugin(&mut self, k: &String, v: &Value) -> Result<()> {
-        let versions = self.parse_plugin_versions(v)?;
+        let mut options = IndexMap::new();
+        let versions = match v {
+            // nodejs = { version = "18", install_env = { NODE_CONFIGURE_OPTS = "--debug" } }
+            Value::Table(t) if t.contains_key("version") => {
+                let versions = self.parse_plugin_versions(&t["version"])?;
+                let opts = self.parse_tool_options(k, t)?;
+                for version in &versions {
+                    options.insert(version.clone(), opts.clone());
+                }
+                versions
+            }
+            // [nodejs.18]
+            // install_env = { NODE_CONFIGURE_OPTS = "--debug" }
+            Value::Table(t) => {
+                let mut versions = vec![];
+                for (version, v) in t.iter() {
+                    match v {
+                        Value::Table(t) => {
+                            options.insert(version.clone(), self.parse_tool_options(k, t)?);
+                        }
+                        _ => Err(eyre!("expected {k}.{version} to be a table, got: {v}"))?,
+                    }
+                    versions.push(version.into());
+                }
+                versions
+            }
+            _ => self.parse_plugin_versions(v)?,
+        };
         self.plugins.insert(
             k.into(),
             Plugin {
                 name: k.into(),
                 versions,
+                options,
             },
         );
         Ok(())
     }
 
+    fn parse_tool_options(
+        &self,
+        plugin: &str,
+        table: &toml::value::Table,
+    ) -> Result<ToolVersionOptions> {
+        let mut options = ToolVersionOptions::default();
+        for (k, v) in table.iter() {
+            match k.as_str() {
+                "version" => {}
+                "install_env" => {
+                    options.install_env = self.parse_string_table(&format!("{plugin}.{k}"), v)?
+                }
+                "bin_paths" => {
+                    options.bin_paths = Some(self.parse_string_array(&format!("{plugin}.{k}"), v)?)
+                }
+                "optional" => options.optional = self.parse_bool(&format!("{plugin}.{k}"), v)?,
+                // project config files should not be able to run commands on install
+                "postinstall" => warn!(
+                    "ignoring {plugin}.postinstall, use a postinstall_{plugin} hook in ~/.config/rtx/config.toml instead"
+                ),
+                _ => warn!("unknown option for {plugin}: {k}"),
+            }
+        }
+        Ok(options)
+    }
+
     fn parse_plugin_versions(&self, v: &Value) -> Result<Vec<String>> {
         match v {
             Value::String(s) => Ok(vec![s.to_string()]),
@@ -153,11 +226,6 @@ impl RTXFile {
                     _ => Err(eyre!("Invalid TOML: {}", v)),
                 })
                 .collect(),
-            Value::Table(t) => t
-                .iter()
-                // TODO: from_file value
-                .map(|(k, _v)| Ok(k.into()))
-                .collect(),
             _ => Err(eyre!(
                 "expected plugin to be a string, array, or table, got: {v}"
             )),
@@ -202,6 +270,24 @@ impl RTXFile {
         }
     }
 
+    fn parse_string_array(&self, k: &str, v: &Value) -> Result<Vec<String>> {
+        match v {
+            Value::String(v) => Ok(vec![v.clone()]),
+            Value::Array(a) => a.iter().map(|v| self.parse_string(k, v)).collect(),
+            _ => Err(eyre!("expected {k} to be a string or array, got: {v}")),
+        }
+    }
+
+    fn parse_string_table(&self, k: &str, v: &Value) -> Result<IndexMap<String, String>> {
+        match v {
+            Value::Table(table) => table
+                .iter()
+                .map(|(key, v)| Ok((key.clone(), self.parse_string(k, v)?)))
+                .collect(),
+            _ => Err(eyre!("expected {k} to be a table, got: {v}")),
+        }
+    }
+
     fn parse_missing_runtime_behavior(&self, v: &Value) -> Result<MissingRuntimeBehavior> {
         let v = self.parse_string("missing_runtime_behavior", v)?;
         match v.to_lowercase().as_str() {
@@ -246,6 +332,72 @@ impl RTXFile {
         }
     }
 
+    /// parses `[plugins]` entries which are either a git url (optionally with `#<ref>`)
+    /// or a table like `{ url = "https://...", ref = "v1.2.0" }`
+    fn parse_plugin_repos(&self, v: &Value) -> Result<IndexMap<PluginName, String>> {
+        match v {
+            Value::Table(table) => {
+                let mut plugins = IndexMap::new();
+                for (plugin, v) in table.iter() {
+                    let key = format!("plugins.{plugin}");
+                    let repo = match v {
+                        Value::String(url) => url.clone(),
+                        Value::Table(table) => {
+                            let url = match table.get("url") {
+                                Some(url) => self.parse_string(&format!("{key}.url"), url)?,
+                                None => Err(eyre!("expected {key} to have a url"))?,
+                            };
+                            match table.get("ref") {
+                                Some(r) => format!("{url}#{}", self.parse_string(&key, r)?),
+                                None => url,
+                            }
+                        }
+                        _ => Err(eyre!("expected {key} to be a string or table, got: {v}"))?,
+                    };
+                    plugins.insert(plugin.into(), repo);
+                }
+                Ok(plugins)
+            }
+            _ => Err(eyre!("expected [plugins] to be a table, got: {v}")),
+        }
+    }
+
+    fn parse_plugin_settings(&self, v: &Value) -> Result<IndexMap<PluginName, PluginSettings>> {
+        match v {
+            Value::Table(table) => {
+                let mut plugin_settings = IndexMap::new();
+                for (plugin, table) in table.iter() {
+                    let ps: &mut PluginSettings = plugin_settings.entry(plugin.into()).or_default();
+                    match table {
+                        Value::Table(table) => {
+                            for (k, v) in table.iter() {
+                                let key = format!("plugin_settings.{plugin}.{k}");
+                                match k.as_str() {
+                                    "include_prereleases" => {
+                                        ps.include_prereleases = Some(self.parse_bool(&key, v)?)
+                                    }
+                                    "prerelease_regex" => {
+                                        ps.prerelease_regex = Some(self.parse_string(&key, v)?)
+                                    }
+                                    "default_packages_file" => {
+                                        ps.default_packages_file =
+                                            Some(self.parse_string(&key, v)?.into())
+                                    }
+                                    _ => Err(eyre!("unknown setting: {key}"))?,
+                                }
+                            }
+                        }
+                        _ => Err(eyre!(
+                            "expected [plugin_settings.{plugin}] to be a table, got: {table}"
+                        ))?,
+                    }
+                }
+                Ok(plugin_settings)
+            }
+            _ => Err(eyre!("expected [plugin_settings] to be a table, got: {v}")),
+        }
+    }
+
     fn get_edit(&self) -> Result<&Mutex<toml_edit::Document>> {
         self.edit.get_or_try_init(|| {
             if !self.path.exists() {
@@ -315,6 +467,19 @@ impl RTXFile {
         Ok(())
     }
 
+    /// records the repository of a plugin in `[plugins]`, e.g.: to pin it to a git ref
+    pub fn set_plugin_repo(&self, plugin: &str, repo: &str) -> Result<()> {
+        let mut doc = self.get_edit()?.lock().unwrap();
+        let plugins = doc
+            .as_table_mut()
+            .entry("plugins")
+            .or_insert(toml_edit::table())
+            .as_table_mut()
+            .unwrap();
+        plugins[plugin] = toml_edit::value(repo);
+        Ok(())
+    }
+
     pub fn remove_alias(&self, plugin: &str, from: &str) -> Result<()> {
         let mut doc = self.get_edit()?.lock().unwrap();
         if let Some(aliases) = doc.get_mut("alias").and_then(|v| v.as_table_mut()) {
@@ -413,7 +578,28 @@ impl ConfigFile for RTXFile {
     }
 
     fn to_toolset(&self) -> Toolset {
-        todo!()
+        self.into()
+    }
+}
+
+impl From<&RTXFile> for Toolset {
+    fn from(value: &RTXFile) -> Self {
+        let mut toolset = Toolset::new(ToolSource::RtxRc(value.path.clone()));
+        toolset.config_env = value
+            .env
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .sorted()
+            .collect();
+        for (plugin, p) in &value.plugins {
+            for version in &p.versions {
+                let v = ToolVersionType::parse(version);
+                let options = p.options.get(version).cloned().unwrap_or_default();
+                let tv = ToolVersion::new(plugin.clone(), v).with_options(options);
+                toolset.add_version(plugin.clone(), tv);
+            }
+        }
+        toolset
     }
 }
 
@@ -493,6 +679,158 @@ packages = ["urchin"]
         assert_eq!(cf.plugins["nodejs"].versions, vec!["20"]);
     }
 
+    #[test]
+    fn test_plugin_options() {
+        let cf = RTXFile::from_str(
+            r#"
+nodejs = { version = "18", install_env = { NODE_CONFIGURE_OPTS = "--debug" }, optional = true }
+[python."3.11"]
+bin_paths = ["bin", "sbin"]
+"#
+            .to_string(),
+        )
+        .unwrap();
+
+        let nodejs = &cf.plugins["nodejs"];
+        assert_eq!(nodejs.versions, vec!["18"]);
+        assert_eq!(
+            nodejs.options["18"].install_env["NODE_CONFIGURE_OPTS"],
+            "--debug"
+        );
+        assert!(nodejs.options["18"].optional);
+        let python = &cf.plugins["python"];
+        assert_eq!(python.versions, vec!["3.11"]);
+        assert_eq!(
+            python.options["3.11"].bin_paths,
+            Some(vec!["bin".to_string(), "sbin".to_string()])
+        );
+        let toolset = cf.to_toolset();
+        assert_eq!(
+            toolset.versions["nodejs"].versions[0].options,
+            nodejs.options["18"]
+        );
+    }
+
+    #[test]
+    fn test_plugin_options_ignores_postinstall() {
+        let cf = RTXFile::from_str(
+            r#"ruby = { version = "3.2", postinstall = "gem install bundler" }"#.to_string(),
+        )
+        .unwrap();
+
+        let toolset = cf.to_toolset();
+        assert!(toolset.versions["ruby"].versions[0].options.is_empty());
+    }
+
+    #[test]
+    fn test_plugin_options_per_version() {
+        let cf = RTXFile::from_str(
+            r#"
+[nodejs."18"]
+install_env = { NODE_CONFIGURE_OPTS = "--debug" }
+[nodejs."20"]
+install_env = { NODE_MAKE_OPTS = "-j4" }
+bin_paths = ["bin", "sbin"]
+"#
+            .to_string(),
+        )
+        .unwrap();
+
+        let toolset = cf.to_toolset();
+        let versions = &toolset.versions["nodejs"].versions;
+        assert_eq!(versions.len(), 2);
+        let (v18, v20) = (&versions[0].options, &versions[1].options);
+        assert_eq!(
+            v18.install_env.keys().collect_vec(),
+            ["NODE_CONFIGURE_OPTS"]
+        );
+        assert_eq!(v18.bin_paths, None);
+        assert_eq!(v20.install_env.keys().collect_vec(), ["NODE_MAKE_OPTS"]);
+        assert_eq!(v20.bin_paths, Some(vec!["bin".into(), "sbin".into()]));
+    }
+
+    #[test]
+    fn test_invalid_plugin_options() {
+        let err = RTXFile::from_str(
+            r#"
+nodejs = { version = "18", optional = "yes" }
+"#
+            .to_string(),
+        )
+        .unwrap_err();
+
+        assert_display_snapshot!(err, @"expected nodejs.optional to be a boolean, got: \"yes\"");
+    }
+
+    #[test]
+    fn test_to_toolset() {
+        let cf = RTXFile::from_str(
+            r#"
+jobs = 2
+nodejs = ["18", "prefix:16"]
+python = "ref:main"
+shellcheck = "system"
+"#
+            .to_string(),
+        )
+        .unwrap();
+        let toolset = cf.to_toolset();
+
+        assert_display_snapshot!(toolset, @"Toolset: nodejs@18 nodejs@prefix:16, python@ref:main, shellcheck@system");
+        assert_eq!(cf.settings().jobs, 2);
+    }
+
+    #[test]
+    fn test_plugin_settings() {
+        let cf = RTXFile::from_str(
+            r#"
+include_prereleases = false
+prerelease_regex = "-rc"
+[plugin_settings.nodejs]
+include_prereleases = true
+"#
+            .to_string(),
+        )
+        .unwrap();
+        let settings = cf.settings();
+
+        assert!(settings.include_prereleases("nodejs"));
+        assert!(!settings.include_prereleases("python"));
+        assert_eq!(settings.prerelease_regex("nodejs").unwrap(), "-rc");
+    }
+
+    #[test]
+    fn test_hooks() {
+        let cf = RTXFile::from_str(
+            r#"
+[hooks]
+post_asdf_install_nodejs = "corepack enable"
+"#
+            .to_string(),
+        )
+        .unwrap();
+        let settings = cf.settings();
+
+        assert_eq!(
+            settings.hooks.get("post_asdf_install_nodejs").unwrap(),
+            "corepack enable"
+        );
+    }
+
+    #[test]
+    fn test_invalid_plugin_settings() {
+        let err = RTXFile::from_str(
+            r#"
+[plugin_settings.nodejs]
+foo = true
+"#
+            .to_string(),
+        )
+        .unwrap_err();
+
+        assert_display_snapshot!(err, @"unknown setting: plugin_settings.nodejs.foo");
+    }
+
     #[test]
     fn test_env() {
         let cf = RTXFile::from_str(
@@ -507,6 +845,29 @@ foo="bar"
         assert_eq!(cf.env["foo"], "bar");
     }
 
+    #[test]
+    fn test_env_to_toolset() {
+        let cf = RTXFile::from_str(
+            r#"
+nodejs = "18"
+[env]
+foo="bar"
+abc="xyz"
+"#
+            .to_string(),
+        )
+        .unwrap();
+        let toolset = cf.to_toolset();
+
+        assert_eq!(
+            toolset.config_env.into_iter().collect::<Vec<_>>(),
+            vec![
+                ("abc".to_string(), "xyz".to_string()),
+                ("foo".to_string(), "bar".to_string())
+            ]
+        );
+    }
+
     #[test]
     fn test_invalid_env() {
         let err = RTXFile::from_str(
@@ -678,6 +1039,70 @@ nodejs=[true]
         "###);
     }
 
+    #[test]
+    fn test_plugin_repos() {
+        let cf = RTXFile::from_str(
+            r#"
+[plugins]
+nodejs = "https://github.com/my-org/asdf-nodejs.git#v1.2.0"
+private = { url = "git@example.com:private.git", ref = "main" }
+tiny = { url = "https://github.com/jdxcode/rtx-tiny.git" }
+"#
+            .to_string(),
+        )
+        .unwrap();
+        let settings = cf.settings();
+
+        assert_eq!(settings.plugin_ref("nodejs"), Some("v1.2.0"));
+        assert_eq!(
+            settings.plugins["private"],
+            "git@example.com:private.git#main"
+        );
+        assert_eq!(
+            settings.plugins["tiny"],
+            "https://github.com/jdxcode/rtx-tiny.git"
+        );
+    }
+
+    #[test]
+    fn test_invalid_plugin_repos() {
+        let err = RTXFile::from_str(
+            r#"
+[plugins]
+nodejs = { ref = "v1.2.0" }
+"#
+            .to_string(),
+        )
+        .unwrap_err();
+
+        assert_display_snapshot!(err, @"expected plugins.nodejs to have a url");
+    }
+
+    #[test]
+    fn test_set_plugin_repo() {
+        let mut f = tempfile::NamedTempFile::new().unwrap();
+        writedoc!(
+            f,
+            r#"
+            [plugins]
+            tiny = "https://github.com/jdxcode/rtx-tiny.git"
+        "#
+        )
+        .unwrap();
+        let cf = RTXFile::from_file(f.path()).unwrap();
+        assert_eq!(cf.settings().plugin_ref("tiny"), None);
+        cf.set_plugin_repo("tiny", "https://github.com/jdxcode/rtx-tiny.git#v1.0")
+            .unwrap();
+        cf.save().unwrap();
+
+        let cf = RTXFile::from_file(f.path()).unwrap();
+        assert_eq!(cf.settings().plugin_ref("tiny"), Some("v1.0"));
+        assert_display_snapshot!(cf.dump(), @r###"
+        [plugins]
+        tiny = "https://github.com/jdxcode/rtx-tiny.git#v1.0"
+        "###);
+    }
+
     #[test]
     fn test_edit_when_file_does_not_exist() {
         let mut cf = RTXFile::from_str("".to_string()).unwrap();
@@ -88,7 +88,7 @@ impl ToolVersions {
                 let plugin = plugin.trim_end_matches(':');
 
                 let tvp = ToolVersionPlugin {
-                    versions: parts.map(|v| v.to_string()).collect(),
+                    versions: parse_versions(parts),
                     post: match post {
                         "" => String::from("\n"),
                         _ => [" #", post, "\n"].join(""),
@@ -101,18 +101,34 @@ impl ToolVersions {
     }
 }
 
+/// splits the versions on a line, keeping bounded ranges like `>=18.12 <20` together
+///
+/// a comparator is only joined to the previous one if that is a lower bound and this is an upper
+/// bound or vice versa, so `^16 >=18` is still 2 versions (the second is the fallback)
+fn parse_versions<'a>(parts: impl Iterator<Item = &'a str>) -> Vec<String> {
+    let mut versions: Vec<String> = vec![];
+    for part in parts {
+        let bounds = |prev: &str| match (prev.chars().next(), part.chars().next()) {
+            (Some('>'), Some('<')) | (Some('<'), Some('>')) => !prev.contains(' '),
+            _ => false,
+        };
+        match versions.last_mut() {
+            Some(prev) if bounds(prev) => {
+                prev.push(' ');
+                prev.push_str(part);
+            }
+            _ => versions.push(part.to_string()),
+        }
+    }
+    versions
+}
+
 impl From<&ToolVersions> for Toolset {
     fn from(value: &ToolVersions) -> Self {
         let mut toolset = Toolset::new(ToolSource::ToolVersions(value.path.clone()));
         for (plugin, tvp) in &value.plugins {
             for version in &tvp.versions {
-                let v = match version.split_once(':') {
-                    Some(("prefix", v)) => ToolVersionType::Prefix(v.to_string()),
-                    Some(("ref", v)) => ToolVersionType::Ref(v.to_string()),
-                    Some(("path", v)) => ToolVersionType::Path(v.to_string()),
-                    None if version == "system" => ToolVersionType::System,
-                    _ => ToolVersionType::Version(version.to_string()),
-                };
+                let v = ToolVersionType::parse(version);
                 toolset.add_version(plugin.clone(), ToolVersion::new(plugin.clone(), v));
             }
         }
@@ -253,6 +269,27 @@ pub(crate) mod tests {
         assert_display_snapshot!(toolset, @"Toolset: ruby@3.0.5 ruby@3.1");
     }
 
+    #[test]
+    fn test_parse_ranges() {
+        let orig = indoc! {"
+        nodejs >=18.12 <20 ^16
+        python ~3.10.4
+        "};
+        let tv = ToolVersions::parse_str(orig).unwrap();
+        let toolset: Toolset = (&tv).into();
+        assert_display_snapshot!(toolset, @"Toolset: nodejs@>=18.12 <20 nodejs@^16, python@~3.10.4");
+        assert_eq!(tv.dump(), orig);
+    }
+
+    #[test]
+    fn test_parse_range_fallbacks() {
+        let tv = ToolVersions::parse_str("nodejs ^16 >=18 >=14 <15 <12\n").unwrap();
+        assert_eq!(
+            tv.plugins["nodejs"].versions,
+            vec!["^16", ">=18", ">=14 <15", "<12"]
+        );
+    }
+
     #[derive(Debug)]
     pub struct MockToolVersions {
         pub path: PathBuf,
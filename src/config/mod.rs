@@ -13,12 +13,14 @@ use rayon::prelude::*;
 pub use settings::{MissingRuntimeBehavior, Settings};
 
 use crate::config::config_file::rtxrc::RTXFile;
+use crate::config::config_file::ConfigFileType;
 use crate::plugins::{Plugin, PluginName};
 use crate::shorthands::{get_shorthands, Shorthands};
 use crate::{dirs, env, file};
 
 pub mod config_file;
 mod settings;
+pub mod tracking;
 
 type AliasMap = IndexMap<PluginName, IndexMap<String, String>>;
 
@@ -40,7 +42,9 @@ impl Config {
         let plugins = load_plugins()?;
         let legacy_files = load_legacy_files(&settings, &plugins);
         let config_files = find_all_config_files(&legacy_files);
+        let settings = load_local_settings(&rtxrc, &config_files)?;
         let aliases = load_aliases(&settings, &plugins);
+        track_config_files(&config_files);
 
         let config = Self {
             settings,
@@ -82,6 +86,68 @@ fn load_rtxrc() -> Result<RTXFile> {
     Ok(rtxrc)
 }
 
+/// the settings in ~/.config/rtx/config.toml, for commands that run before the rest of the
+/// config is loaded
+pub fn load_global_settings() -> Settings {
+    match load_rtxrc() {
+        Ok(rtxrc) => rtxrc.settings(),
+        Err(err) => {
+            debug!("failed to load settings: {:#}", err);
+            Settings::default()
+        }
+    }
+}
+
+/// merges settings from any .rtx.toml files into the global settings
+/// files closer to the current directory take precedence
+///
+/// `[hooks]` and `default_packages_file` are only read from the global config since they run
+/// arbitrary commands (or pass arguments to them) and a project's config file should not be able
+/// to run them just by being in the directory
+fn load_local_settings(rtxrc: &RTXFile, config_files: &[PathBuf]) -> Result<Settings> {
+    let mut settings = rtxrc.settings_builder().clone();
+    let global_config = dirs::CONFIG.join("config.toml");
+    for path in config_files.iter().rev() {
+        if config_file::detect_config_file_type(path) != Some(ConfigFileType::RtxRc) {
+            continue;
+        }
+        let cf = RTXFile::from_file(path).wrap_err_with(|| err_load_settings(path))?;
+        let mut local = cf.settings_builder().clone();
+        if local.hooks.take().is_some() {
+            warn!(
+                "ignoring [hooks] in {}, hooks can only be set in {}",
+                file::display_path(path),
+                file::display_path(&global_config)
+            );
+        }
+        for (plugin, ps) in local.plugin_settings.iter_mut().flatten() {
+            if ps.default_packages_file.take().is_some() {
+                warn!(
+                    "ignoring [plugin_settings.{plugin}] default_packages_file in {}, it can only be set in {}",
+                    file::display_path(path),
+                    file::display_path(&global_config)
+                );
+            }
+            // keep the global one since the local plugin settings replace them
+            ps.default_packages_file = settings
+                .plugin_settings
+                .as_ref()
+                .and_then(|global| global.get(plugin))
+                .and_then(|global| global.default_packages_file.clone());
+        }
+        settings.merge(local);
+    }
+    Ok(settings.build())
+}
+
+fn track_config_files(config_files: &[PathBuf]) {
+    for path in config_files {
+        if let Err(err) = tracking::track(path) {
+            debug!("failed to track config file {}: {:#}", path.display(), err);
+        }
+    }
+}
+
 fn load_plugins() -> Result<IndexMap<PluginName, Arc<Plugin>>> {
     let plugins = Plugin::list()?
         .into_par_iter()
@@ -124,8 +190,7 @@ fn load_legacy_files(
 
 fn find_all_config_files(legacy_filenames: &IndexMap<String, PluginName>) -> Vec<PathBuf> {
     let mut filenames = vec![
-        // ".rtxrc.toml",
-        // ".rtxrc",
+        env::RTX_DEFAULT_CONFIG_FILENAME.as_str(),
         env::RTX_DEFAULT_TOOL_VERSIONS_FILENAME.as_str(),
     ];
     for filename in legacy_filenames.keys() {
@@ -219,4 +284,50 @@ mod tests {
         let config = Config::load().unwrap();
         assert_display_snapshot!(config);
     }
+
+    #[test]
+    fn test_load_local_settings() {
+        let dir = tempfile::tempdir().unwrap();
+        let parent = dir.path().join(".test.rtx.toml");
+        let child = dir.path().join("child").join(".test.rtx.toml");
+        std::fs::create_dir_all(child.parent().unwrap()).unwrap();
+        std::fs::write(&parent, "jobs = 3\nalways_keep_download = true\n").unwrap();
+        std::fs::write(&child, "jobs = 5\n").unwrap();
+
+        let settings = load_local_settings(&RTXFile::default(), &[child, parent]).unwrap();
+        assert_eq!(settings.jobs, 5);
+        assert!(settings.always_keep_download);
+    }
+
+    #[test]
+    fn test_load_local_settings_ignores_hooks() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(".test.rtx.toml");
+        std::fs::write(&path, "[hooks]\npostinstall = 'echo hi'\n").unwrap();
+        let rtxrc =
+            RTXFile::from_str("[hooks]\npost_asdf_install = 'echo global'\n".into()).unwrap();
+
+        let settings = load_local_settings(&rtxrc, &[path]).unwrap();
+        assert_eq!(settings.hooks.len(), 1);
+        assert_eq!(settings.hooks["post_asdf_install"], "echo global");
+    }
+
+    #[test]
+    fn test_load_local_settings_ignores_default_packages_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(".test.rtx.toml");
+        let local = "[plugin_settings.tiny]\ninclude_prereleases = true\ndefault_packages_file = 'packages'\n";
+        std::fs::write(&path, local).unwrap();
+        let rtxrc = RTXFile::from_str(
+            "[plugin_settings.tiny]\ndefault_packages_file = '/global/packages'\n".into(),
+        )
+        .unwrap();
+
+        let settings = load_local_settings(&rtxrc, &[path]).unwrap();
+        assert!(settings.include_prereleases("tiny"));
+        assert_eq!(
+            settings.default_packages_file("tiny"),
+            Some(PathBuf::from("/global/packages"))
+        );
+    }
 }
@@ -2,15 +2,19 @@ use std::fmt::{Display, Formatter};
 use std::path::PathBuf;
 use std::time::Duration;
 
+use color_eyre::eyre::Result;
 use indexmap::IndexMap;
 use log::LevelFilter;
 
 use crate::config::AliasMap;
+use crate::dirs;
 use crate::env;
 use crate::env::{
-    RTX_ASDF_COMPAT, RTX_DISABLE_DEFAULT_SHORTHANDS, RTX_JOBS, RTX_LOG_LEVEL, RTX_SHIMS_DIR,
-    RTX_SHORTHANDS_FILE, RTX_VERBOSE,
+    RTX_ASDF_COMPAT, RTX_DISABLE_DEFAULT_SHORTHANDS, RTX_JOBS, RTX_LOCKFILE, RTX_LOG_LEVEL,
+    RTX_OFFLINE, RTX_PREFER_INSTALLED, RTX_SHIMS_DIR, RTX_SHORTHANDS_FILE, RTX_VERBOSE,
 };
+use crate::errors::Error::Offline;
+use crate::git::split_url_ref;
 use crate::plugins::PluginName;
 
 #[derive(Debug, Clone)]
@@ -28,6 +32,24 @@ pub struct Settings {
     pub disable_default_shorthands: bool,
     pub log_level: LevelFilter,
     pub shims_dir: Option<PathBuf>,
+    pub lockfile: bool,
+    pub offline: bool,
+    pub prefer_installed: bool,
+    pub include_prereleases: bool,
+    pub prerelease_regex: Option<String>,
+    pub plugin_settings: IndexMap<PluginName, PluginSettings>,
+    pub hooks: IndexMap<String, String>,
+    /// plugin repositories from `[plugins]`, optionally pinned with `#<ref>`
+    pub plugins: IndexMap<PluginName, String>,
+}
+
+/// settings that can be overridden for a specific plugin
+/// e.g.: `[plugin_settings.nodejs]` in ~/.config/rtx/config.toml
+#[derive(Debug, Default, Clone)]
+pub struct PluginSettings {
+    pub include_prereleases: Option<bool>,
+    pub prerelease_regex: Option<String>,
+    pub default_packages_file: Option<PathBuf>,
 }
 
 impl Default for Settings {
@@ -46,6 +68,14 @@ impl Default for Settings {
             disable_default_shorthands: *RTX_DISABLE_DEFAULT_SHORTHANDS,
             log_level: *RTX_LOG_LEVEL,
             shims_dir: RTX_SHIMS_DIR.clone(),
+            lockfile: *RTX_LOCKFILE,
+            offline: *RTX_OFFLINE,
+            prefer_installed: *RTX_PREFER_INSTALLED,
+            include_prereleases: false,
+            prerelease_regex: None,
+            plugin_settings: IndexMap::new(),
+            hooks: IndexMap::new(),
+            plugins: IndexMap::new(),
         }
     }
 }
@@ -87,8 +117,62 @@ impl Settings {
         if let Some(shims) = &self.shims_dir {
             map.insert("shims_dir".into(), shims.to_string_lossy().to_string());
         }
+        map.insert("lockfile".into(), self.lockfile.to_string());
+        map.insert("offline".into(), self.offline.to_string());
+        map.insert("prefer_installed".into(), self.prefer_installed.to_string());
+        map.insert(
+            "include_prereleases".into(),
+            self.include_prereleases.to_string(),
+        );
+        if let Some(prerelease_regex) = &self.prerelease_regex {
+            map.insert("prerelease_regex".into(), prerelease_regex.clone());
+        }
         map
     }
+
+    /// errors if rtx is offline, for actions that cannot work without network access
+    pub fn ensure_online(&self, action: &str) -> Result<()> {
+        match self.offline {
+            true => Err(Offline(action.to_string()))?,
+            false => Ok(()),
+        }
+    }
+
+    /// whether prerelease versions (e.g.: 1.0.0-rc1) should be considered for a plugin
+    pub fn include_prereleases(&self, plugin_name: &str) -> bool {
+        self.plugin_settings
+            .get(plugin_name)
+            .and_then(|ps| ps.include_prereleases)
+            .unwrap_or(self.include_prereleases)
+    }
+
+    /// custom regex used to detect prerelease versions for a plugin
+    pub fn prerelease_regex(&self, plugin_name: &str) -> Option<&String> {
+        self.plugin_settings
+            .get(plugin_name)
+            .and_then(|ps| ps.prerelease_regex.as_ref())
+            .or(self.prerelease_regex.as_ref())
+    }
+
+    /// the git ref a plugin is pinned to in `[plugins]`, if any
+    pub fn plugin_ref(&self, plugin_name: &str) -> Option<&str> {
+        let repo = self.plugins.get(plugin_name)?;
+        split_url_ref(repo).1
+    }
+
+    /// file listing packages to install after installing a new version of a plugin
+    /// e.g.: `default_packages_file = "~/.default-npm-packages"`
+    pub fn default_packages_file(&self, plugin_name: &str) -> Option<PathBuf> {
+        let file = self
+            .plugin_settings
+            .get(plugin_name)?
+            .default_packages_file
+            .as_ref()?;
+        match file.strip_prefix("~") {
+            Ok(file) => Some(dirs::HOME.join(file)),
+            Err(_) => Some(file.clone()),
+        }
+    }
 }
 
 #[derive(Debug, Default, Clone)]
@@ -106,6 +190,14 @@ pub struct SettingsBuilder {
     pub disable_default_shorthands: Option<bool>,
     pub log_level: Option<LevelFilter>,
     pub shims_dir: Option<PathBuf>,
+    pub lockfile: Option<bool>,
+    pub offline: Option<bool>,
+    pub prefer_installed: Option<bool>,
+    pub include_prereleases: Option<bool>,
+    pub prerelease_regex: Option<String>,
+    pub plugin_settings: Option<IndexMap<PluginName, PluginSettings>>,
+    pub hooks: Option<IndexMap<String, String>>,
+    pub plugins: Option<IndexMap<PluginName, String>>,
 }
 
 impl SettingsBuilder {
@@ -115,7 +207,7 @@ impl SettingsBuilder {
     //     }
     // }
 
-    pub fn _merge(&mut self, other: Self) -> &mut Self {
+    pub fn merge(&mut self, other: Self) -> &mut Self {
         if other.experimental.is_some() {
             self.experimental = other.experimental;
         }
@@ -153,6 +245,36 @@ impl SettingsBuilder {
         if other.shims_dir.is_some() {
             self.shims_dir = other.shims_dir;
         }
+        if other.lockfile.is_some() {
+            self.lockfile = other.lockfile;
+        }
+        if other.offline.is_some() {
+            self.offline = other.offline;
+        }
+        if other.prefer_installed.is_some() {
+            self.prefer_installed = other.prefer_installed;
+        }
+        if other.include_prereleases.is_some() {
+            self.include_prereleases = other.include_prereleases;
+        }
+        if other.prerelease_regex.is_some() {
+            self.prerelease_regex = other.prerelease_regex;
+        }
+        if let Some(plugin_settings) = other.plugin_settings {
+            // merged per plugin so project config files don't drop the global plugin settings
+            self.plugin_settings
+                .get_or_insert_with(IndexMap::new)
+                .extend(plugin_settings);
+        }
+        if other.hooks.is_some() {
+            self.hooks = other.hooks;
+        }
+        if let Some(plugins) = other.plugins {
+            // plugins are merged so project config files can add to the global ones
+            self.plugins
+                .get_or_insert_with(IndexMap::new)
+                .extend(plugins);
+        }
         if other.aliases.is_some() {
             self.aliases = other.aliases;
         }
@@ -194,6 +316,19 @@ impl SettingsBuilder {
             .unwrap_or(settings.disable_default_shorthands);
         settings.log_level = self.log_level.unwrap_or(settings.log_level);
         settings.shims_dir = self.shims_dir.clone().or(settings.shims_dir);
+        settings.lockfile = self.lockfile.unwrap_or(settings.lockfile);
+        settings.offline = *RTX_OFFLINE || self.offline.unwrap_or(settings.offline);
+        settings.prefer_installed = self.prefer_installed.unwrap_or(settings.prefer_installed);
+        settings.include_prereleases = self
+            .include_prereleases
+            .unwrap_or(settings.include_prereleases);
+        settings.prerelease_regex = self.prerelease_regex.clone().or(settings.prerelease_regex);
+        settings.plugin_settings = self
+            .plugin_settings
+            .clone()
+            .unwrap_or(settings.plugin_settings);
+        settings.hooks = self.hooks.clone().unwrap_or(settings.hooks);
+        settings.plugins = self.plugins.clone().unwrap_or(settings.plugins);
         settings.aliases = self.aliases.clone().unwrap_or(settings.aliases);
 
         settings
@@ -231,11 +366,98 @@ mod tests {
             missing_runtime_behavior: Some(AutoInstall),
             ..SettingsBuilder::default()
         };
-        s1._merge(s2);
+        s1.merge(s2);
 
         assert_eq!(s1.missing_runtime_behavior, Some(AutoInstall));
     }
 
+    #[test]
+    fn test_settings_merge_plugins() {
+        let mut s1 = SettingsBuilder {
+            plugins: Some(IndexMap::from([
+                ("nodejs".into(), "https://nodejs".into()),
+                ("python".into(), "https://python".into()),
+            ])),
+            ..SettingsBuilder::default()
+        };
+        s1.merge(SettingsBuilder {
+            plugins: Some(IndexMap::from([(
+                "nodejs".into(),
+                "https://nodejs-fork#v1".into(),
+            )])),
+            ..SettingsBuilder::default()
+        });
+        let settings = s1.build();
+
+        assert_eq!(settings.plugins["nodejs"], "https://nodejs-fork#v1");
+        assert_eq!(settings.plugins["python"], "https://python");
+        assert_eq!(settings.plugin_ref("nodejs"), Some("v1"));
+        assert_eq!(settings.plugin_ref("python"), None);
+    }
+
+    #[test]
+    fn test_settings_merge_plugin_settings() {
+        let plugin_settings = |regex: &str| PluginSettings {
+            prerelease_regex: Some(regex.into()),
+            ..PluginSettings::default()
+        };
+        let mut s1 = SettingsBuilder {
+            plugin_settings: Some(IndexMap::from([
+                ("nodejs".into(), plugin_settings("-beta")),
+                ("python".into(), plugin_settings("-dev")),
+            ])),
+            ..SettingsBuilder::default()
+        };
+        s1.merge(SettingsBuilder {
+            plugin_settings: Some(IndexMap::from([(
+                "python".into(),
+                plugin_settings("a[0-9]"),
+            )])),
+            ..SettingsBuilder::default()
+        });
+        let settings = s1.build();
+
+        assert_eq!(settings.prerelease_regex("nodejs").unwrap(), "-beta");
+        assert_eq!(settings.prerelease_regex("python").unwrap(), "a[0-9]");
+    }
+
+    #[test]
+    fn test_plugin_settings() {
+        let settings = SettingsBuilder {
+            prerelease_regex: Some("-rc".into()),
+            plugin_settings: Some(IndexMap::from([(
+                "nodejs".into(),
+                PluginSettings {
+                    include_prereleases: Some(true),
+                    prerelease_regex: Some("-beta".into()),
+                    ..PluginSettings::default()
+                },
+            )])),
+            ..SettingsBuilder::default()
+        }
+        .build();
+
+        assert!(settings.include_prereleases("nodejs"));
+        assert!(!settings.include_prereleases("python"));
+        assert_eq!(settings.prerelease_regex("nodejs").unwrap(), "-beta");
+        assert_eq!(settings.prerelease_regex("python").unwrap(), "-rc");
+    }
+
+    #[test]
+    fn test_ensure_online() {
+        let settings = SettingsBuilder {
+            offline: Some(true),
+            ..SettingsBuilder::default()
+        }
+        .build();
+        let err = settings.ensure_online("update plugins").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "cannot update plugins while offline, unset RTX_OFFLINE or the offline setting to continue"
+        );
+        assert!(Settings::default().ensure_online("update plugins").is_ok());
+    }
+
     #[test]
     fn test_missing_runtime_behavior_display() {
         assert_eq!(AutoInstall.to_string(), "autoinstall");
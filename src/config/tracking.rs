@@ -0,0 +1,113 @@
+use std::collections::HashSet;
+use std::fs;
+use std::os::unix::fs::symlink;
+use std::path::{Path, PathBuf};
+
+use color_eyre::eyre::Result;
+
+use crate::config::Config;
+use crate::dirs;
+use crate::hash::hash_to_str;
+use crate::plugins::PluginName;
+use crate::toolset::ToolsetBuilder;
+
+/// records that a config file has been used so `rtx prune` knows its runtimes are still needed
+///
+/// each tracked file is a symlink in ~/.local/share/rtx/tracked_config_files
+pub fn track(path: &Path) -> Result<()> {
+    let tracking_path = dirs::TRACKED_CONFIGS.join(hash_to_str(&path));
+    if !tracking_path.is_symlink() {
+        fs::create_dir_all(&*dirs::TRACKED_CONFIGS)?;
+        symlink(path, tracking_path)?;
+    }
+    Ok(())
+}
+
+/// all of the tracked config files that still exist
+///
+/// links to config files that have been deleted are removed
+pub fn list() -> Result<Vec<PathBuf>> {
+    let mut config_files = vec![];
+    if !dirs::TRACKED_CONFIGS.exists() {
+        return Ok(config_files);
+    }
+    for entry in fs::read_dir(&*dirs::TRACKED_CONFIGS)? {
+        let tracking_path = entry?.path();
+        let path = fs::read_link(&tracking_path)?;
+        if path.exists() {
+            config_files.push(path);
+        } else {
+            trace!("removing stale tracked config file {}", path.display());
+            let _ = fs::remove_file(tracking_path);
+        }
+    }
+    config_files.sort();
+    Ok(config_files)
+}
+
+/// the installed versions referenced by the tracked config files or the ones in the current
+/// directory, as (plugin, version)
+///
+/// versions are matched against what is installed rather than resolved from the remote versions
+/// so `nodejs 18` still references an installed 18.14.0 after 18.15.0 is released
+pub fn installed_versions_in_use(config: &Config) -> Result<HashSet<(PluginName, String)>> {
+    let mut config_files = list()?;
+    config_files.extend(config.config_files.iter().cloned());
+    let mut in_use = HashSet::new();
+    for path in config_files {
+        let ts = ToolsetBuilder::new()
+            .with_config_files(vec![path])
+            .without_resolve()
+            .build(config);
+        for (plugin_name, tvl) in ts.versions {
+            if let Some(plugin) = config.plugins.get(&plugin_name) {
+                for tv in tvl.versions {
+                    for v in tv.installed_matches(&config.settings, plugin.clone())? {
+                        in_use.insert((plugin_name.clone(), v));
+                    }
+                }
+            }
+        }
+    }
+    Ok(in_use)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::env;
+    use crate::lockfile::Lockfile;
+
+    use super::*;
+
+    #[test]
+    fn test_tracking() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(".tool-versions");
+        fs::write(&path, "tiny 3\n").unwrap();
+        track(&path).unwrap();
+        track(&path).unwrap();
+        assert!(list().unwrap().contains(&path));
+
+        fs::remove_file(&path).unwrap();
+        assert!(!list().unwrap().contains(&path));
+    }
+
+    #[test]
+    fn test_installed_versions_in_use() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir
+            .path()
+            .join(env::RTX_DEFAULT_TOOL_VERSIONS_FILENAME.as_str());
+        fs::write(&path, "tiny 1.0\n").unwrap();
+        let mut lockfile = Lockfile::load(&path).unwrap();
+        let tiny = lockfile.plugins.entry("tiny".into()).or_default();
+        tiny.versions.insert("1.0".into(), "1.0.0".into());
+        lockfile.save().unwrap();
+        track(&path).unwrap();
+
+        let config = Config::load().unwrap();
+        let in_use = installed_versions_in_use(&config).unwrap();
+        assert!(in_use.contains(&("tiny".into(), "1.0.0".into())));
+        assert!(in_use.contains(&("tiny".into(), "1.0.1".into())));
+    }
+}
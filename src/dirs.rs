@@ -13,4 +13,6 @@ lazy_static! {
     pub static ref PLUGINS: PathBuf = env::RTX_DATA_DIR.join("plugins");
     pub static ref DOWNLOADS: PathBuf = env::RTX_DATA_DIR.join("downloads");
     pub static ref INSTALLS: PathBuf = env::RTX_DATA_DIR.join("installs");
+    pub static ref TRACKED_CONFIGS: PathBuf = env::RTX_DATA_DIR.join("tracked_config_files");
+    pub static ref PLUGIN_HISTORY: PathBuf = env::RTX_DATA_DIR.join("plugin_history");
 }
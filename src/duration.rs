@@ -0,0 +1,40 @@
+use std::time::Duration;
+
+use color_eyre::eyre::{eyre, Result};
+
+/// parses a human duration like "90d", "12h", "2w", or "30m"
+/// a plain number is treated as seconds
+pub fn parse_duration(s: &str) -> Result<Duration> {
+    let s = s.trim();
+    let (n, unit) = s.split_at(s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len()));
+    let n: u64 = n.parse().map_err(|_| eyre!("invalid duration: {s}"))?;
+    let secs = match unit {
+        "" | "s" => 1,
+        "m" => 60,
+        "h" => 60 * 60,
+        "d" => 60 * 60 * 24,
+        "w" => 60 * 60 * 24 * 7,
+        _ => {
+            return Err(eyre!(
+                "invalid duration: {s}, use a suffix like 30m, 12h, 90d or 2w"
+            ))
+        }
+    };
+    Ok(Duration::from_secs(n * secs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("90").unwrap(), Duration::from_secs(90));
+        assert_eq!(parse_duration("30m").unwrap(), Duration::from_secs(1800));
+        assert_eq!(parse_duration("12h").unwrap(), Duration::from_secs(43200));
+        assert_eq!(parse_duration("90d").unwrap(), Duration::from_secs(7776000));
+        assert_eq!(parse_duration("2w").unwrap(), Duration::from_secs(1209600));
+        assert!(parse_duration("d").is_err());
+        assert!(parse_duration("3y").is_err());
+    }
+}
@@ -118,6 +118,11 @@ lazy_static! {
     } else {
         var("RTX_DEFAULT_TOOL_VERSIONS_FILENAME").unwrap_or_else(|_| ".tool-versions".into())
     };
+    pub static ref RTX_DEFAULT_CONFIG_FILENAME: String = if cfg!(test) {
+        ".test.rtx.toml".into()
+    } else {
+        var("RTX_DEFAULT_CONFIG_FILENAME").unwrap_or_else(|_| ".rtx.toml".into())
+    };
     pub static ref DIRENV_DIR: Option<String> = var("DIRENV_DIR").ok();
     pub static ref DIRENV_DIFF: Option<String> = var("DIRENV_DIFF").ok();
     pub static ref RTX_HIDE_OUTDATED_BUILD: bool = var_is_true("RTX_HIDE_OUTDATED_BUILD");
@@ -126,6 +131,9 @@ lazy_static! {
     pub static ref RTX_SHORTHANDS_FILE: Option<PathBuf> = var_path("RTX_SHORTHANDS_FILE");
     pub static ref RTX_DISABLE_DEFAULT_SHORTHANDS: bool = var_is_true("RTX_DISABLE_DEFAULT_SHORTHANDS");
     pub static ref RTX_SHIMS_DIR: Option<PathBuf> = var_path("RTX_SHIMS_DIR");
+    pub static ref RTX_LOCKFILE: bool = var_is_true("RTX_LOCKFILE");
+    pub static ref RTX_OFFLINE: bool = var_is_true("RTX_OFFLINE");
+    pub static ref RTX_PREFER_INSTALLED: bool = var_is_true("RTX_PREFER_INSTALLED");
     pub static ref GITHUB_API_TOKEN: Option<String> = var("GITHUB_API_TOKEN").ok();
 }
 
@@ -11,10 +11,11 @@ pub enum Error {
     #[error("{0}@{1} not installed")]
     VersionNotInstalled(PluginName, String),
     #[error("{0}@{1} not found")]
-    #[allow(dead_code)]
     VersionNotFound(PluginName, String),
     #[error("[{}] script exited with non-zero status: {}", .0, render_exit_status(.1))]
     ScriptFailed(PluginName, Option<ExitStatus>),
+    #[error("cannot {0} while offline, unset RTX_OFFLINE or the offline setting to continue")]
+    Offline(String),
 }
 
 fn render_exit_status(exit_status: &Option<ExitStatus>) -> String {
@@ -57,6 +57,19 @@ pub fn dir_subdirs(dir: &Path) -> Result<Vec<String>> {
     Ok(output)
 }
 
+/// total size in bytes of all the files in a directory, symlinks are not followed
+pub fn disk_usage(path: &Path) -> Result<u64> {
+    let metadata = path.symlink_metadata()?;
+    if !metadata.is_dir() {
+        return Ok(metadata.len());
+    }
+    let mut size = 0;
+    for entry in path.read_dir()? {
+        size += disk_usage(&entry?.path())?;
+    }
+    Ok(size)
+}
+
 pub fn dir_files(dir: &Path) -> Result<Vec<String>> {
     let mut output = vec![];
 
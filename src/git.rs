@@ -36,18 +36,27 @@ impl Git {
     pub fn update(&self, gitref: Option<String>) -> Result<(String, String)> {
         let gitref = gitref.map_or_else(|| self.remote_default_branch(), Ok)?;
         debug!("updating {} to {}", self.dir.display(), gitref);
+        // read before fetching since fetch updates the branch if it is checked out
+        let prev_rev = self.current_sha()?;
         cmd!(
             "git",
             "-C",
             &self.dir,
             "fetch",
+            "-q",
             "--prune",
             "--update-head-ok",
             "origin",
             [gitref.as_str(), gitref.as_str()].join(":"),
         )
         .run()?;
-        let prev_rev = self.current_sha()?;
+        let post_rev = self.checkout(&gitref)?;
+
+        Ok((prev_rev, post_rev))
+    }
+
+    /// checks out a ref that has already been fetched, returning the new sha
+    pub fn checkout(&self, gitref: &str) -> Result<String> {
         cmd!(
             "git",
             "-C",
@@ -57,17 +66,18 @@ impl Git {
             "-c",
             "advice.objectNameWarning=false",
             "checkout",
+            "-q",
             "--force",
             gitref
         )
         .run()?;
-        let post_rev = self.current_sha()?;
         touch_dir(&self.dir)?;
-
-        Ok((prev_rev, post_rev))
+        self.current_sha()
     }
 
+    /// clones the repository, `url` may end with `#<ref>` to check out a specific branch, tag, or sha
     pub fn clone(&self, url: &str) -> Result<()> {
+        let (url, gitref) = split_url_ref(url);
         debug!("cloning {} to {}", url, self.dir.display());
         if let Some(parent) = self.dir.parent() {
             create_dir_all(parent)?;
@@ -80,6 +90,9 @@ impl Git {
             ),
         }
         cmd!("git", "clone", "-q", "--depth", "1", url, &self.dir).run()?;
+        if let Some(gitref) = gitref {
+            self.update(Some(gitref.to_string()))?;
+        }
         Ok(())
     }
 
@@ -95,6 +108,18 @@ impl Git {
         Ok(sha)
     }
 
+    /// subjects of the commits between two shas, newest first
+    pub fn log_subjects(&self, from: &str, to: &str) -> Result<Vec<String>> {
+        let range = format!("{from}..{to}");
+        let log = cmd!("git", "-C", &self.dir, "log", "--format=%s", range).read()?;
+        Ok(log.lines().map(String::from).collect())
+    }
+
+    pub fn subject(&self, sha: &str) -> Result<String> {
+        let subject = cmd!("git", "-C", &self.dir, "log", "-1", "--format=%s", sha).read()?;
+        Ok(subject)
+    }
+
     pub fn get_remote_url(&self) -> Option<String> {
         let res = cmd!(
             "git",
@@ -122,30 +147,68 @@ impl Git {
     }
 }
 
+/// abbreviates a sha for display, e.g.: "c85ab2bea15e8b78..." -> "c85ab2b"
+pub fn short_sha(sha: &str) -> &str {
+    &sha[..sha.len().min(7)]
+}
+
+/// splits a plugin repository into its url and optional git ref
+/// e.g.: "https://github.com/asdf-vm/asdf-nodejs.git#v1.2.0"
+pub fn split_url_ref(url: &str) -> (&str, Option<&str>) {
+    match url.rsplit_once('#') {
+        Some((url, gitref)) if !gitref.is_empty() => (url, Some(gitref)),
+        Some((url, _)) => (url, None),
+        None => (url, None),
+    }
+}
+
 fn get_git_version() -> Result<String> {
     let version = cmd!("git", "--version").read()?;
     Ok(version.trim().into())
 }
 
-// #[cfg(test)]
-// mod tests {
-//     use pretty_assertions::assert_str_eq;
-//     use tempfile::tempdir;
-//
-//     use super::*;
-//
-//     #[test]
-//     fn test_clone_and_update() {
-//         let dir = tempdir().unwrap().into_path();
-//         let git = Git::new(dir);
-//         git.clone("https://github.com/jdxcode/rtx-tiny")
-//             .unwrap();
-//         let prev_rev = "c85ab2bea15e8b785592ce1a75db341e38ac4d33".to_string();
-//         let latest = git.current_sha().unwrap();
-//         let update_result = git.update(Some(prev_rev.clone())).unwrap();
-//         assert_eq!(update_result, (latest.to_string(), prev_rev.to_string()));
-//         assert_str_eq!(git.current_sha_short().unwrap(), "c85ab2b");
-//         let update_result = git.update(None).unwrap();
-//         assert_eq!(update_result, (prev_rev, latest));
-//     }
-// }
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_str_eq;
+    use tempfile::tempdir;
+
+    use crate::test::run_git;
+
+    use super::*;
+
+    #[test]
+    fn test_split_url_ref() {
+        let url = "https://github.com/jdxcode/rtx-tiny.git";
+        assert_eq!(split_url_ref(url), (url, None));
+        assert_eq!(split_url_ref(&format!("{url}#")), (url, None));
+        assert_eq!(split_url_ref(&format!("{url}#v1.0")), (url, Some("v1.0")));
+    }
+
+    #[test]
+    fn test_clone_and_update() {
+        let origin = tempdir().unwrap();
+        run_git(origin.path(), &["init", "-q", "-b", "main"]);
+        run_git(origin.path(), &["commit", "-q", "--allow-empty", "-m", "1"]);
+        run_git(origin.path(), &["tag", "v1"]);
+        let v1 = run_git(origin.path(), &["rev-parse", "HEAD"]);
+        run_git(origin.path(), &["commit", "-q", "--allow-empty", "-m", "2"]);
+        let main = run_git(origin.path(), &["rev-parse", "HEAD"]);
+
+        let dir = tempdir().unwrap();
+        let git = Git::new(dir.path().join("plugin"));
+        let url = format!("file://{}", origin.path().display());
+        git.clone(&format!("{url}#v1")).unwrap();
+        assert_str_eq!(git.current_sha().unwrap(), v1);
+        let update_result = git.update(None).unwrap();
+        assert_eq!(update_result, (v1.clone(), main.clone()));
+        assert_eq!(git.log_subjects(&v1, &main).unwrap(), vec!["2"]);
+        assert_str_eq!(git.subject(&v1).unwrap(), "1");
+
+        run_git(origin.path(), &["commit", "-q", "--allow-empty", "-m", "3"]);
+        let head = run_git(origin.path(), &["rev-parse", "HEAD"]);
+        let update_result = git.update(None).unwrap();
+        assert_eq!(update_result, (main, head.clone()));
+        let update_result = git.update(Some(v1.clone())).unwrap();
+        assert_eq!(update_result, (head, v1));
+    }
+}
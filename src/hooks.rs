@@ -0,0 +1,72 @@
+use color_eyre::eyre::{Result, WrapErr};
+use duct::Expression;
+
+use crate::cmd;
+use crate::config::Settings;
+use crate::errors::Error::ScriptFailed;
+
+/// runs an asdf-compatible hook from the `[hooks]` section of ~/.config/rtx/config.toml
+/// e.g.: `post_asdf_plugin_add = "echo added $1"`
+///
+/// both the generic hook and the plugin-specific one (e.g.: `pre_asdf_install_nodejs`) are run
+/// if defined. Hooks are run with bash and receive `args` as positional parameters.
+pub fn run(settings: &Settings, plugin_name: &str, hook: &str, args: &[&str]) -> Result<()> {
+    for (hook, script) in list(settings, plugin_name, hook) {
+        debug!("running hook {hook}: {script}");
+        let output = cmd(script, args)
+            .stdout_to_stderr()
+            .unchecked()
+            .run()
+            .wrap_err_with(|| format!("failed to run hook {hook}"))?;
+        if !output.status.success() {
+            return Err(ScriptFailed(plugin_name.to_string(), Some(output.status)))
+                .wrap_err_with(|| format!("hook {hook} failed"));
+        }
+    }
+    Ok(())
+}
+
+/// the generic and plugin-specific scripts defined for a hook, in the order they run
+pub fn list<'a>(
+    settings: &'a Settings,
+    plugin_name: &str,
+    hook: &str,
+) -> Vec<(String, &'a String)> {
+    [hook.to_string(), format!("{hook}_{plugin_name}")]
+        .into_iter()
+        .filter_map(|hook| settings.hooks.get(&hook).map(|script| (hook, script)))
+        .collect()
+}
+
+/// builds a command that runs a hook script with bash, passing `args` as positional parameters
+pub fn cmd(script: &str, args: &[&str]) -> Expression {
+    let mut cmd_args = vec!["-c", script, "bash"];
+    cmd_args.extend(args);
+    cmd::cmd("bash", cmd_args)
+}
+
+#[cfg(test)]
+mod tests {
+    use indexmap::IndexMap;
+
+    use super::*;
+
+    #[test]
+    fn test_run_hook() {
+        let settings = Settings {
+            hooks: IndexMap::from([
+                ("pre_asdf_install".into(), "test \"$1\" = 1.0.0".into()),
+                ("pre_asdf_install_tiny".into(), "exit 1".into()),
+            ]),
+            ..Settings::default()
+        };
+        run(&settings, "dummy", "pre_asdf_install", &["1.0.0"]).unwrap();
+        run(&settings, "dummy", "post_asdf_install", &[]).unwrap();
+        let err = run(&settings, "tiny", "pre_asdf_install", &["1.0.0"]).unwrap_err();
+        assert_eq!(err.to_string(), "hook pre_asdf_install_tiny failed");
+        assert_eq!(
+            err.root_cause().to_string(),
+            "[tiny] script exited with non-zero status: exit code 1"
+        );
+    }
+}
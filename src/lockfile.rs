@@ -0,0 +1,276 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use color_eyre::eyre::{eyre, Result, WrapErr};
+use indexmap::IndexMap;
+use itertools::Itertools;
+use serde_derive::{Deserialize, Serialize};
+
+use crate::config::Config;
+use crate::file::display_path;
+use crate::git::Git;
+use crate::toolset::{ToolSource, ToolVersion, ToolVersionType, Toolset};
+
+const HEADER: &str = "# this file is generated by rtx, do not edit it manually\n\n";
+
+/// records the exact versions that fuzzy versions in a config file resolved to
+/// as well as the git sha of the plugin used to install them
+///
+/// lockfiles live next to the config file they lock, e.g.: `.tool-versions.lock`
+#[derive(Debug, Default)]
+pub struct Lockfile {
+    pub path: PathBuf,
+    pub plugins: BTreeMap<String, LockedPlugin>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockedPlugin {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub plugin_sha: Option<String>,
+    /// maps the version as written in the config file to the version it resolved to
+    #[serde(default)]
+    pub versions: BTreeMap<String, String>,
+}
+
+impl Lockfile {
+    pub fn path_for(config_path: &Path) -> PathBuf {
+        let mut filename = config_path.file_name().unwrap_or_default().to_os_string();
+        filename.push(".lock");
+        config_path.with_file_name(filename)
+    }
+
+    pub fn load(config_path: &Path) -> Result<Self> {
+        let path = Self::path_for(config_path);
+        let plugins = match path.exists() {
+            true => {
+                trace!("parsing lockfile: {}", path.display());
+                let body = fs::read_to_string(&path)?;
+                toml::from_str(&body)
+                    .wrap_err_with(|| eyre!("error parsing {}", display_path(&path)))?
+            }
+            false => BTreeMap::new(),
+        };
+        Ok(Self { path, plugins })
+    }
+
+    pub fn exists(&self) -> bool {
+        self.path.exists()
+    }
+
+    pub fn get(&self, plugin: &str, version: &ToolVersionType) -> Option<&String> {
+        self.plugins
+            .get(plugin)
+            .and_then(|p| p.versions.get(&version.to_string()))
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let body = toml::to_string_pretty(&self.plugins)?;
+        fs::write(&self.path, format!("{HEADER}{body}"))?;
+        Ok(())
+    }
+}
+
+/// sets the locked version on any tool versions that came from a config file with a lockfile
+pub fn apply(ts: &mut Toolset) {
+    for (plugin, tvl) in ts.versions.iter_mut() {
+        let path = match config_path(&tvl.source) {
+            Some(path) => path,
+            None => continue,
+        };
+        let lockfile = match Lockfile::load(&path) {
+            Ok(lockfile) => lockfile,
+            Err(err) => {
+                warn!("error loading lockfile: {:#}", err);
+                continue;
+            }
+        };
+        for tv in tvl.versions.iter_mut().filter(|tv| is_lockable(tv)) {
+            tv.locked = lockfile.get(plugin, &tv.r#type).cloned();
+        }
+    }
+}
+
+/// errors if any locked tool versions are missing from their lockfile
+/// or if an installed plugin is at a different sha than the one recorded
+///
+/// config files without a lockfile are not checked, but at least one of them must have one
+pub fn verify(config: &Config, ts: &Toolset) -> Result<()> {
+    let versions_by_file = lockable_versions_by_file(ts);
+    let mut verified = false;
+    for (path, versions) in &versions_by_file {
+        let lockfile = Lockfile::load(path)?;
+        if !lockfile.exists() {
+            continue;
+        }
+        verified = true;
+        for tv in versions {
+            if tv.locked.is_none() {
+                return Err(eyre!(
+                    "{tv} from {} is not in {}. Run `rtx install` without --frozen to update it",
+                    display_path(path),
+                    display_path(&lockfile.path),
+                ));
+            }
+            let expected = lockfile
+                .plugins
+                .get(&tv.plugin_name)
+                .and_then(|p| p.plugin_sha.as_ref());
+            if let (Some(expected), Some(actual)) = (expected, plugin_sha(config, &tv.plugin_name))
+            {
+                if expected != &actual {
+                    return Err(eyre!(
+                        "plugin {} is at {actual} but {} expects {expected}",
+                        tv.plugin_name,
+                        display_path(&lockfile.path),
+                    ));
+                }
+            }
+        }
+    }
+    if !verified && !versions_by_file.is_empty() {
+        let paths = versions_by_file.keys().map(|p| display_path(p)).join(", ");
+        return Err(eyre!(
+            "no lockfile found for {paths}. Run `rtx install` with RTX_LOCKFILE=1 to create one"
+        ));
+    }
+    Ok(())
+}
+
+/// returns true if a lockfile should be written for the config file,
+/// i.e.: the `lockfile` setting is enabled or it already has one
+pub fn is_enabled(config: &Config, config_path: &Path) -> bool {
+    config.settings.lockfile || Lockfile::path_for(config_path).exists()
+}
+
+/// writes the resolved versions to the lockfile next to each config file
+///
+/// lockfiles are only written if they already exist or the `lockfile` setting is enabled
+pub fn update(config: &Config, ts: &Toolset) -> Result<()> {
+    for (path, versions) in lockable_versions_by_file(ts) {
+        if !is_enabled(config, &path) {
+            continue;
+        }
+        let mut lockfile = Lockfile::load(&path)?;
+        let orig = lockfile.plugins.clone();
+        for tv in versions {
+            let rtv = match &tv.rtv {
+                Some(rtv) => rtv,
+                None => continue,
+            };
+            let locked = lockfile.plugins.entry(tv.plugin_name.clone()).or_default();
+            locked.plugin_sha = plugin_sha(config, &tv.plugin_name);
+            locked
+                .versions
+                .insert(tv.r#type.to_string(), rtv.version.clone());
+        }
+        if lockfile.plugins != orig || !lockfile.exists() {
+            debug!("writing lockfile: {}", display_path(&lockfile.path));
+            lockfile.save()?;
+        }
+    }
+    Ok(())
+}
+
+/// returns true if the lockfile of one of the current config files records the plugin's git sha
+pub fn locks_plugin_sha(config: &Config, plugin_name: &str) -> bool {
+    config
+        .config_files
+        .iter()
+        .any(|path| match Lockfile::load(path) {
+            Ok(lockfile) => lockfile
+                .plugins
+                .get(plugin_name)
+                .and_then(|p| p.plugin_sha.as_ref())
+                .is_some(),
+            Err(_) => false,
+        })
+}
+
+/// versions that may resolve differently over time and therefore need to be locked
+fn is_lockable(tv: &ToolVersion) -> bool {
+    matches!(
+        tv.r#type,
+        ToolVersionType::Version(_) | ToolVersionType::Prefix(_) | ToolVersionType::Range(_)
+    )
+}
+
+fn config_path(source: &ToolSource) -> Option<PathBuf> {
+    match source {
+        ToolSource::ToolVersions(path) | ToolSource::RtxRc(path) => Some(path.clone()),
+        _ => None,
+    }
+}
+
+fn lockable_versions_by_file(ts: &Toolset) -> IndexMap<PathBuf, Vec<&ToolVersion>> {
+    let mut versions: IndexMap<PathBuf, Vec<&ToolVersion>> = IndexMap::new();
+    for tvl in ts.versions.values() {
+        if let Some(path) = config_path(&tvl.source) {
+            versions
+                .entry(path)
+                .or_default()
+                .extend(tvl.versions.iter().filter(|tv| is_lockable(tv)));
+        }
+    }
+    versions
+}
+
+fn plugin_sha(config: &Config, plugin_name: &str) -> Option<String> {
+    let plugin = config.plugins.get(plugin_name)?;
+    let git = Git::new(plugin.plugin_path.clone());
+    match git.is_repo() {
+        true => git.current_sha().ok(),
+        false => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use insta::assert_snapshot;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_path_for() {
+        let path = Lockfile::path_for(Path::new("/foo/.tool-versions"));
+        assert_eq!(path, PathBuf::from("/foo/.tool-versions.lock"));
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let dir = tempfile::tempdir().unwrap();
+        let config_path = dir.path().join(".tool-versions");
+        let mut lockfile = Lockfile::load(&config_path).unwrap();
+        assert!(!lockfile.exists());
+        let nodejs = lockfile.plugins.entry("nodejs".into()).or_default();
+        nodejs.plugin_sha = Some("abc123".into());
+        nodejs.versions.insert("18".into(), "18.14.0".into());
+        nodejs.versions.insert("prefix:16".into(), "16.19.1".into());
+        lockfile.save().unwrap();
+
+        assert_snapshot!(fs::read_to_string(&lockfile.path).unwrap(), @r###"
+        # this file is generated by rtx, do not edit it manually
+
+        [nodejs]
+        plugin_sha = "abc123"
+
+        [nodejs.versions]
+        18 = "18.14.0"
+        "prefix:16" = "16.19.1"
+        "###);
+        let lockfile = Lockfile::load(&config_path).unwrap();
+        let version = ToolVersionType::Prefix("16".into());
+        assert_eq!(lockfile.get("nodejs", &version).unwrap(), "16.19.1");
+    }
+
+    #[test]
+    fn test_load_invalid() {
+        let dir = tempfile::tempdir().unwrap();
+        let config_path = dir.path().join(".tool-versions");
+        fs::write(Lockfile::path_for(&config_path), indoc! {"nodejs = 1"}).unwrap();
+        let err = Lockfile::load(&config_path).unwrap_err();
+        assert!(err.to_string().starts_with("error parsing"));
+    }
+}
@@ -31,6 +31,7 @@ mod fake_asdf;
 mod file;
 mod git;
 mod hook_env;
+mod lockfile;
 mod logger;
 mod plugins;
 pub mod runtimes;
@@ -40,7 +41,9 @@ mod shorthands;
 mod ui;
 
 mod direnv;
+mod duration;
 mod hash;
+mod hooks;
 mod toolset;
 
 #[cfg(test)]
@@ -0,0 +1,100 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fs;
+use std::path::Path;
+
+use color_eyre::eyre::Result;
+use itertools::Itertools;
+
+use crate::config::Settings;
+use crate::runtimes::RuntimeVersion;
+use crate::ui::progress_report::ProgressReport;
+
+/// lists and installs the versions of a tool
+///
+/// asdf plugins implement this with shell scripts (see `ScriptBackend`), core tools can implement
+/// it in rust and register themselves in `plugins::core`
+///
+/// caching, version matching, and the install/uninstall directories are handled by `Plugin` and
+/// `RuntimeVersion` so backends only need to provide the tool-specific parts
+pub trait Backend: Debug + Send + Sync {
+    /// every version that can be installed, in any order
+    fn list_remote_versions(&self, settings: &Settings) -> Result<Vec<String>>;
+
+    /// the latest stable version matching `query`, if the backend knows better than filtering
+    /// out prereleases from `list_remote_versions`
+    fn latest_stable(&self, _settings: &Settings, _query: &str) -> Result<Option<String>> {
+        Ok(None)
+    }
+
+    /// aliases for versions, e.g.: `lts -> 18`
+    fn list_aliases(&self, _settings: &Settings) -> Result<Vec<(String, String)>> {
+        Ok(vec![])
+    }
+
+    /// files other version managers use to set the version, e.g.: `.nvmrc`
+    fn list_legacy_filenames(&self, _settings: &Settings) -> Result<Vec<String>> {
+        Ok(vec![])
+    }
+
+    /// reads the version out of one of the files in `list_legacy_filenames`
+    fn parse_legacy_file(&self, legacy_file: &Path, _settings: &Settings) -> Result<String> {
+        Ok(fs::read_to_string(legacy_file)?.trim().to_string())
+    }
+
+    /// downloads and installs a version into `rtv.install_path`
+    ///
+    /// the install and download directories have already been created when this is called
+    fn install_version(
+        &self,
+        settings: &Settings,
+        rtv: &RuntimeVersion,
+        pr: &ProgressReport,
+    ) -> Result<()>;
+
+    /// runs before a version's directories are removed
+    fn uninstall_version(&self, _rtv: &RuntimeVersion) -> Result<()> {
+        Ok(())
+    }
+
+    /// directories with executables in them, relative to the install path
+    fn list_bin_paths(&self, _rtv: &RuntimeVersion) -> Result<Vec<String>> {
+        Ok(vec!["bin".into()])
+    }
+
+    /// the path to run for `bin_name`, relative to the install path
+    ///
+    /// `path` is where `bin_name` was found in `list_bin_paths`, this can point it somewhere else
+    /// for tools where the executables in the bin paths are wrappers
+    fn exec_path(&self, _rtv: &RuntimeVersion, _bin_name: &str, path: &str) -> Result<String> {
+        Ok(path.into())
+    }
+
+    /// env vars to set when the version is in use, besides adding its bin paths to PATH
+    fn exec_env(&self, _rtv: &RuntimeVersion) -> Result<HashMap<String, String>> {
+        Ok(HashMap::new())
+    }
+
+    /// documentation for `rtx plugins info` as `(topic, text)`, the topics are the ones asdf
+    /// uses: overview, deps, config, and links
+    fn help(&self) -> Result<Vec<(String, String)>> {
+        Ok(vec![])
+    }
+}
+
+/// parses the output of list-aliases, one `<alias> <version>` per line
+pub fn parse_aliases(output: &str) -> Vec<(String, String)> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.split_whitespace().collect_vec();
+            if parts.len() != 2 {
+                if !parts.is_empty() {
+                    trace!("invalid alias line: {}", line);
+                }
+                return None;
+            }
+            Some((parts.remove(0).into(), parts.remove(0).into()))
+        })
+        .collect()
+}
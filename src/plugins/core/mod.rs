@@ -0,0 +1,20 @@
+use std::sync::Arc;
+
+use indexmap::IndexMap;
+use once_cell::sync::Lazy;
+
+use crate::plugins::{Backend, PluginName};
+
+/// tools implemented in rust rather than with an asdf plugin, by the name they are used with
+///
+/// to add one, implement `Backend` in a module next to this one and insert it here
+static CORE_PLUGINS: Lazy<IndexMap<PluginName, Arc<dyn Backend>>> = Lazy::new(IndexMap::new);
+
+/// the core plugin named `name`, if there is one
+pub fn get(name: &str) -> Option<Arc<dyn Backend>> {
+    CORE_PLUGINS.get(name).cloned()
+}
+
+pub fn list() -> Vec<PluginName> {
+    CORE_PLUGINS.keys().cloned().collect()
+}
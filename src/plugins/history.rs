@@ -0,0 +1,104 @@
+use std::fs;
+use std::path::PathBuf;
+
+use chrono::{DateTime, Local};
+use color_eyre::eyre::{eyre, Result};
+use itertools::Itertools;
+
+use crate::dirs;
+
+/// how many shas to keep for each plugin
+const MAX_ENTRIES: usize = 20;
+
+/// a git sha a plugin was at and when it was checked out
+///
+/// each plugin's history is stored in ~/.local/share/rtx/plugin_history/<PLUGIN>
+/// with one "<date> <sha>" line per entry, oldest first
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HistoryEntry {
+    pub sha: String,
+    pub date: DateTime<Local>,
+}
+
+pub fn load(plugin_name: &str) -> Result<Vec<HistoryEntry>> {
+    let path = history_path(plugin_name);
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+    fs::read_to_string(&path)?
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let (date, sha) = line
+                .split_once(' ')
+                .ok_or_else(|| eyre!("invalid plugin history entry: {line}"))?;
+            let date = DateTime::parse_from_rfc3339(date)?.with_timezone(&Local);
+            Ok(HistoryEntry {
+                sha: sha.to_string(),
+                date,
+            })
+        })
+        .collect()
+}
+
+/// adds a sha to the plugin's history unless the plugin was already at it
+pub fn record(plugin_name: &str, sha: &str) -> Result<()> {
+    let mut entries = load(plugin_name)?;
+    if entries.last().map(|e| e.sha.as_str()) == Some(sha) {
+        return Ok(());
+    }
+    entries.push(HistoryEntry {
+        sha: sha.to_string(),
+        date: Local::now(),
+    });
+    let skip = entries.len().saturating_sub(MAX_ENTRIES);
+    let body = entries
+        .iter()
+        .skip(skip)
+        .map(|e| format!("{} {}\n", e.date.to_rfc3339(), e.sha))
+        .join("");
+    fs::create_dir_all(&*dirs::PLUGIN_HISTORY)?;
+    fs::write(history_path(plugin_name), body)?;
+    Ok(())
+}
+
+pub fn remove(plugin_name: &str) -> Result<()> {
+    let path = history_path(plugin_name);
+    if path.exists() {
+        fs::remove_file(path)?;
+    }
+    Ok(())
+}
+
+fn history_path(plugin_name: &str) -> PathBuf {
+    dirs::PLUGIN_HISTORY.join(plugin_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_history() {
+        let plugin_name = "history-test";
+        remove(plugin_name).unwrap();
+        assert!(load(plugin_name).unwrap().is_empty());
+
+        record(plugin_name, "aaa").unwrap();
+        record(plugin_name, "aaa").unwrap();
+        record(plugin_name, "bbb").unwrap();
+        let shas = load(plugin_name).unwrap().into_iter().map(|e| e.sha);
+        assert_eq!(shas.collect_vec(), vec!["aaa", "bbb"]);
+
+        for i in 0..MAX_ENTRIES {
+            record(plugin_name, &i.to_string()).unwrap();
+        }
+        let entries = load(plugin_name).unwrap();
+        assert_eq!(entries.len(), MAX_ENTRIES);
+        assert_eq!(entries[0].sha, "0");
+
+        remove(plugin_name).unwrap();
+    }
+}
@@ -0,0 +1,155 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::Path;
+
+use color_eyre::eyre::{eyre, Result, WrapErr};
+use indexmap::IndexMap;
+use itertools::Itertools;
+use serde_derive::Deserialize;
+
+use crate::file::display_path;
+
+pub const MANIFEST_FILENAME: &str = "rtx-plugin.toml";
+
+/// an optional `rtx-plugin.toml` in the root of a plugin which declares what some of its scripts
+/// would output, so rtx can read it instead of running them, e.g.:
+///
+/// ```toml
+/// legacy_filenames = [".nvmrc", ".node-version"]
+/// bin_paths = ["bin"]
+///
+/// [aliases]
+/// lts = "18"
+///
+/// [exec_env]
+/// NODE_HOME = "$ASDF_INSTALL_PATH"
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+pub struct PluginManifest {
+    /// used instead of bin/list-legacy-filenames
+    pub legacy_filenames: Option<Vec<String>>,
+    /// used instead of bin/list-aliases
+    pub aliases: Option<BTreeMap<String, String>>,
+    /// used instead of bin/list-bin-paths
+    pub bin_paths: Option<Vec<String>>,
+    /// used instead of bin/exec-env, `$ASDF_*` vars in the values are expanded
+    pub exec_env: Option<BTreeMap<String, String>>,
+    /// used instead of the default prerelease regex, the `prerelease_regex` setting still
+    /// takes precedence
+    pub prerelease_regex: Option<String>,
+}
+
+impl PluginManifest {
+    /// an empty manifest if the plugin does not have one or it can't be parsed
+    pub fn load(plugin_path: &Path) -> Self {
+        let path = plugin_path.join(MANIFEST_FILENAME);
+        if !path.exists() {
+            return Self::default();
+        }
+        match Self::parse(&path) {
+            Ok(manifest) => manifest,
+            Err(err) => {
+                warn!("{:#}", err);
+                Self::default()
+            }
+        }
+    }
+
+    fn parse(path: &Path) -> Result<Self> {
+        trace!("parsing plugin manifest: {}", path.display());
+        let body = fs::read_to_string(path)?;
+        toml::from_str(&body).wrap_err_with(|| eyre!("error parsing {}", display_path(path)))
+    }
+
+    /// `exec_env` with the `$ASDF_*` vars in `env` expanded
+    pub fn expand_exec_env(
+        &self,
+        env: &IndexMap<String, String>,
+    ) -> Option<HashMap<String, String>> {
+        let exec_env = self.exec_env.as_ref()?;
+        // longest first so $ASDF_INSTALL_PATH is not expanded as $ASDF_INSTALL
+        let vars = env
+            .iter()
+            .filter(|(k, _)| k.starts_with("ASDF_"))
+            .sorted_by_key(|(k, _)| Reverse(k.len()))
+            .collect_vec();
+        let expand = |value: &String| {
+            vars.iter().fold(value.clone(), |value, (k, v)| {
+                value
+                    .replace(&format!("${{{k}}}"), v)
+                    .replace(&format!("${k}"), v)
+            })
+        };
+        Some(
+            exec_env
+                .iter()
+                .map(|(k, v)| (k.clone(), expand(v)))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indexmap::indexmap;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_load() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(PluginManifest::load(dir.path()), PluginManifest::default());
+        fs::write(
+            dir.path().join(MANIFEST_FILENAME),
+            indoc! {r#"
+                legacy_filenames = [".nvmrc", ".node-version"]
+                bin_paths = ["bin", "libexec/bin"]
+
+                [aliases]
+                lts = "18"
+            "#},
+        )
+        .unwrap();
+        let manifest = PluginManifest::load(dir.path());
+        assert_eq!(
+            manifest.legacy_filenames.unwrap(),
+            vec![".nvmrc", ".node-version"]
+        );
+        assert_eq!(manifest.bin_paths.unwrap(), vec!["bin", "libexec/bin"]);
+        assert_eq!(manifest.aliases.unwrap()["lts"], "18");
+        assert_eq!(manifest.exec_env, None);
+    }
+
+    #[test]
+    fn test_load_invalid() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join(MANIFEST_FILENAME), "bin_paths = 1").unwrap();
+        let err = PluginManifest::parse(&dir.path().join(MANIFEST_FILENAME)).unwrap_err();
+        assert!(err.to_string().starts_with("error parsing"));
+        assert_eq!(PluginManifest::load(dir.path()), PluginManifest::default());
+    }
+
+    #[test]
+    fn test_expand_exec_env() {
+        let manifest = PluginManifest {
+            exec_env: Some(BTreeMap::from([
+                ("TINY_HOME".into(), "$ASDF_INSTALL_PATH".into()),
+                ("TINY_LIB".into(), "${ASDF_INSTALL_PATH}/lib".into()),
+                ("TINY_HOMEPAGE".into(), "$HOME".into()),
+            ])),
+            ..PluginManifest::default()
+        };
+        let env = indexmap! {
+            "ASDF_INSTALL".to_string() => "/wrong".to_string(),
+            "ASDF_INSTALL_PATH".to_string() => "/installs/tiny/1.0.0".to_string(),
+            "HOME".to_string() => "/home".to_string(),
+        };
+        let exec_env = manifest.expand_exec_env(&env).unwrap();
+        assert_eq!(exec_env["TINY_HOME"], "/installs/tiny/1.0.0");
+        assert_eq!(exec_env["TINY_LIB"], "/installs/tiny/1.0.0/lib");
+        assert_eq!(exec_env["TINY_HOMEPAGE"], "$HOME");
+    }
+}
@@ -1,11 +1,13 @@
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::process::exit;
-use std::time::Duration;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
 
 use color_eyre::eyre::WrapErr;
 use color_eyre::eyre::{eyre, Result};
 use console::style;
+use filetime::{set_file_mtime, FileTime};
 use indexmap::IndexMap;
 use indicatif::ProgressStyle;
 use itertools::Itertools;
@@ -14,49 +16,94 @@ use once_cell::sync::Lazy;
 use regex::Regex;
 use versions::Versioning;
 
+pub use backend::Backend;
+pub use history::HistoryEntry;
+pub use manifest::{PluginManifest, MANIFEST_FILENAME};
+pub use script_backend::ScriptBackend;
 pub use script_manager::{InstallType, Script, ScriptManager};
+#[cfg(feature = "wasm")]
+pub use wasm_backend::WasmBackend;
 
 use crate::cache::CacheManager;
 use crate::cmd::cmd;
 use crate::config::{Config, Settings};
+use crate::env;
 use crate::env::RTX_PREFER_STALE;
 use crate::errors::Error::PluginNotInstalled;
-use crate::git::Git;
+use crate::git::{short_sha, Git};
 use crate::hash::hash_to_str;
-use crate::plugins::script_manager::Script::ParseLegacyFile;
+use crate::hooks;
 use crate::ui::progress_report::ProgressReport;
-use crate::{dirs, file};
+use crate::{dirs, file, lockfile};
 
+mod backend;
+pub mod core;
+mod history;
+mod manifest;
+mod script_backend;
 mod script_manager;
+#[cfg(feature = "wasm")]
+mod wasm_backend;
 
 pub type PluginName = String;
 
-/// This represents a plugin installed to ~/.local/share/rtx/plugins
+/// versions matching this are excluded from fuzzy matches and "latest"
+/// can be overridden with the `prerelease_regex` setting
+const DEFAULT_PRERELEASE_REGEX: &str = r"(-src|-dev|-latest|-stm|[-\\.]rc|-milestone|-alpha|-beta|[-\\.]pre|-next|(a|b|c)[0-9]+|snapshot|master)";
+
+static PROG_TEMPLATE: Lazy<ProgressStyle> = Lazy::new(|| {
+    ProgressStyle::with_template("{prefix}{wide_msg} {spinner:.blue} {elapsed:.dim.italic}")
+        .unwrap()
+});
+
+/// This represents a plugin installed to ~/.local/share/rtx/plugins or a core plugin
+///
+/// listing and installing versions is done by its `Backend`, this handles caching the results
+/// and managing the plugin itself
 #[derive(Debug, Clone)]
 pub struct Plugin {
     pub name: PluginName,
     pub plugin_path: PathBuf,
+    pub backend: Arc<dyn Backend>,
+    pub manifest: PluginManifest,
+    core: bool,
     cache_path: PathBuf,
     downloads_path: PathBuf,
     installs_path: PathBuf,
-    script_man: ScriptManager,
     remote_version_cache: CacheManager<Vec<String>>,
     alias_cache: CacheManager<Vec<(String, String)>>,
     legacy_filename_cache: CacheManager<Vec<String>>,
+    latest_stable_cache: CacheManager<Option<String>>,
 }
 
 impl Plugin {
+    /// a plugin installed to ~/.local/share/rtx/plugins takes precedence over a core plugin
+    /// with the same name
     pub fn new(name: &PluginName) -> Self {
+        let plugin_path = dirs::PLUGINS.join(name);
+        match core::get(name) {
+            Some(backend) if !plugin_path.exists() => Self::with_backend(name, backend, true),
+            #[cfg(feature = "wasm")]
+            _ if plugin_path.join(wasm_backend::MODULE_FILENAME).exists() => {
+                let backend = Arc::new(WasmBackend::new(name, &plugin_path));
+                Self::with_backend(name, backend, false)
+            }
+            _ => {
+                let backend = Arc::new(ScriptBackend::new(plugin_path));
+                Self::with_backend(name, backend, false)
+            }
+        }
+    }
+
+    fn with_backend(name: &PluginName, backend: Arc<dyn Backend>, core: bool) -> Self {
         let plugin_path = dirs::PLUGINS.join(name);
         let cache_path = dirs::CACHE.join(name);
-        let fresh_duration = if *RTX_PREFER_STALE {
-            None
-        } else {
-            Some(Duration::from_secs(60 * 60 * 24))
-        };
+        let fresh_duration = fresh_duration();
         Self {
             name: name.into(),
-            script_man: ScriptManager::new(plugin_path.clone()),
+            backend,
+            manifest: PluginManifest::load(&plugin_path),
+            core,
             downloads_path: dirs::DOWNLOADS.join(name),
             installs_path: dirs::INSTALLS.join(name),
             remote_version_cache: CacheManager::new(
@@ -73,20 +120,28 @@ impl Plugin {
             )
             .with_fresh_file(plugin_path.clone())
             .with_fresh_file(plugin_path.join("bin/list-legacy-filenames")),
+            latest_stable_cache: CacheManager::new(cache_path.join("latest_stable.msgpack.zlib"))
+                .with_fresh_duration(fresh_duration)
+                .with_fresh_file(plugin_path.clone())
+                .with_fresh_file(plugin_path.join("bin/latest-stable")),
             plugin_path,
             cache_path,
         }
     }
 
     pub fn list() -> Result<Vec<Self>> {
-        Ok(file::dir_subdirs(&dirs::PLUGINS)?
-            .iter()
-            .map(Plugin::new)
+        let mut names = file::dir_subdirs(&dirs::PLUGINS)?;
+        names.extend(core::list());
+        Ok(names
+            .into_iter()
+            .sorted()
+            .dedup()
+            .map(|n| Plugin::new(&n))
             .collect())
     }
 
     pub fn is_installed(&self) -> bool {
-        self.plugin_path.exists()
+        self.core || self.plugin_path.exists()
     }
 
     pub fn get_remote_url(&self) -> Option<String> {
@@ -100,29 +155,34 @@ impl Plugin {
         repository: Option<&String>,
         mut pr: ProgressReport,
     ) -> Result<()> {
-        static PROG_TEMPLATE: Lazy<ProgressStyle> = Lazy::new(|| {
-            ProgressStyle::with_template("{prefix}{wide_msg} {spinner:.blue} {elapsed:.dim.italic}")
-                .unwrap()
-        });
-        pr.set_style(PROG_TEMPLATE.clone());
-        pr.set_prefix(format!(
-            "{} {} ",
-            style("rtx").dim().for_stderr(),
-            style(&self.name).cyan().for_stderr()
-        ));
-        pr.enable_steady_tick();
+        self.init_progress_report(&mut pr);
+        if self.core {
+            return Err(eyre!(
+                "{} is a core plugin, it does not need to be installed",
+                self.name
+            ));
+        }
+        config
+            .settings
+            .ensure_online(&format!("install plugin {}", self.name))?;
         let repository = repository
             .or_else(|| config.get_shorthands().get(&self.name))
             .ok_or_else(|| eyre!("No repository found for plugin {}", self.name))?;
         debug!("install {} {:?}", self.name, repository);
         if self.is_installed() {
             pr.set_message("uninstalling existing plugin".into());
-            self.uninstall()?;
+            self.uninstall(&config.settings)?;
         }
+        self.run_hook(&config.settings, "pre_asdf_plugin_add")?;
 
         let git = Git::new(self.plugin_path.to_path_buf());
         pr.set_message(format!("cloning {repository}"));
         git.clone(repository)?;
+        self.run_lifecycle_script(
+            Script::PostPluginAdd,
+            [("ASDF_PLUGIN_SOURCE_URL".into(), repository.to_string())],
+        )?;
+        self.run_hook(&config.settings, "post_asdf_plugin_add")?;
 
         pr.set_message("loading plugin remote versions".into());
         if self.has_list_all_script() {
@@ -137,6 +197,8 @@ impl Plugin {
             self.legacy_filenames(&config.settings)?;
         }
 
+        self.touch_last_autoupdate()?;
+        self.record_history(&git.current_sha()?);
         let sha = git.current_sha_short()?;
         pr.finish_with_message(format!(
             "{} {repository}@{}",
@@ -146,31 +208,69 @@ impl Plugin {
         Ok(())
     }
 
-    pub fn update(&self, gitref: Option<String>) -> Result<()> {
+    /// updates the plugin's git repository to `gitref` or the default branch
+    ///
+    /// returns the previous and new sha, or None if the plugin can't be updated
+    pub fn update(
+        &self,
+        settings: &Settings,
+        gitref: Option<String>,
+        mut pr: ProgressReport,
+    ) -> Result<Option<(String, String)>> {
+        self.init_progress_report(&mut pr);
         let plugin_path = self.plugin_path.to_path_buf();
+        if self.core {
+            pr.finish_with_message("is a core plugin, not updating".into());
+            return Ok(None);
+        }
         if plugin_path.is_symlink() {
-            warn!(
-                "Plugin: {} is a symlink, not updating",
-                style(&self.name).cyan().for_stderr()
-            );
-            return Ok(());
+            pr.finish_with_message("is a symlink, not updating".into());
+            return Ok(None);
         }
         let git = Git::new(plugin_path);
         if !git.is_repo() {
-            warn!(
-                "Plugin {} is not a git repository, not updating",
-                style(&self.name).cyan().for_stderr()
-            );
-            return Ok(());
+            pr.finish_with_message("is not a git repository, not updating".into());
+            return Ok(None);
         }
-        // TODO: asdf_run_hook "pre_plugin_update"
-        let (_pre, _post) = git.update(gitref)?;
-        // TODO: asdf_run_hook "post_plugin_update"
-        Ok(())
+        self.run_hook(settings, "pre_asdf_plugin_update")?;
+        match &gitref {
+            Some(gitref) => pr.set_message(format!("updating to {gitref}")),
+            None => pr.set_message("updating".into()),
+        }
+        let (pre, post) = git.update(gitref)?;
+        if pre != post {
+            self.clear_cache()?;
+        }
+        self.touch_last_autoupdate()?;
+        self.record_history(&pre);
+        self.record_history(&post);
+        self.run_lifecycle_script(
+            Script::PostPluginUpdate,
+            [
+                ("ASDF_PLUGIN_PREV_REF".into(), pre.clone()),
+                ("ASDF_PLUGIN_POST_REF".into(), post.clone()),
+            ],
+        )?;
+        let args = [self.name.as_str(), &pre, &post];
+        hooks::run(settings, &self.name, "post_asdf_plugin_update", &args)?;
+        let msg = match pre == post {
+            true => "already up to date".to_string(),
+            false => format!("{}..{}", short_sha(&pre), short_sha(&post)),
+        };
+        pr.finish_with_message(format!("{} {msg}", style("✓").green().for_stderr()));
+        Ok(Some((pre, post)))
     }
 
-    pub fn uninstall(&self) -> Result<()> {
+    pub fn uninstall(&self, settings: &Settings) -> Result<()> {
         debug!("uninstall {}", self.name);
+        if self.core {
+            return Err(eyre!(
+                "{} is a core plugin and can't be uninstalled",
+                self.name
+            ));
+        }
+        self.run_hook(settings, "pre_asdf_plugin_remove")?;
+        self.run_lifecycle_script(Script::PrePluginRemove, [])?;
 
         let rmdir = |dir: &Path| {
             if !dir.exists() {
@@ -187,47 +287,195 @@ impl Plugin {
         rmdir(&self.downloads_path)?;
         rmdir(&self.installs_path)?;
         rmdir(&self.plugin_path)?;
+        history::remove(&self.name)?;
 
+        self.run_hook(settings, "post_asdf_plugin_remove")?;
         Ok(())
     }
 
+    fn init_progress_report(&self, pr: &mut ProgressReport) {
+        pr.set_style(PROG_TEMPLATE.clone());
+        pr.set_prefix(format!(
+            "{} {} ",
+            style("rtx").dim().for_stderr(),
+            style(&self.name).cyan().for_stderr()
+        ));
+        pr.enable_steady_tick();
+    }
+
+    fn run_hook(&self, settings: &Settings, hook: &str) -> Result<()> {
+        hooks::run(settings, &self.name, hook, &[&self.name])
+    }
+
+    /// runs an optional plugin script like bin/post-plugin-add, if the plugin provides it
+    fn run_lifecycle_script<I>(&self, script: Script, envs: I) -> Result<()>
+    where
+        I: IntoIterator<Item = (String, String)>,
+    {
+        let script_man = self.script_man();
+        if !script_man.script_exists(&script) {
+            return Ok(());
+        }
+        debug!("running {script} for {}", self.name);
+        let plugin_path = self.plugin_path.to_string_lossy().to_string();
+        script_man
+            .with_env("ASDF_PLUGIN_PATH".into(), plugin_path)
+            .with_envs(envs)
+            .run(script)
+    }
+
     pub fn latest_version(&self, settings: &Settings, query: &str) -> Result<Option<String>> {
         let matches = self.list_versions_matching(settings, query)?;
-        let v = match matches.contains(&query.to_string()) {
-            true => Some(query.to_string()),
-            false => matches.last().map(|v| v.to_string()),
-        };
-        Ok(v)
+        if matches.contains(&query.to_string()) {
+            return Ok(Some(query.to_string()));
+        }
+        if let Some(v) = self.latest_stable(settings, query)? {
+            return Ok(Some(v));
+        }
+        Ok(matches.last().map(|v| v.to_string()))
     }
 
     pub fn list_versions_matching(&self, settings: &Settings, query: &str) -> Result<Vec<String>> {
-        let mut query = query;
-        if query == "latest" {
-            query = "[0-9]";
-        }
-        let version_regex = regex!(
-            r"(^Available versions:|-src|-dev|-latest|-stm|[-\\.]rc|-milestone|-alpha|-beta|[-\\.]pre|-next|(a|b|c)[0-9]+|snapshot|master)"
-        );
+        let (query_regex, include_prereleases) = self.parse_query(settings, query);
+        let versions = self
+            .list_remote_versions_filtered(settings, include_prereleases)?
+            .into_iter()
+            .filter(|v| query_regex.is_match(v))
+            .collect_vec();
+        Ok(versions)
+    }
+
+    /// like list_versions_matching but only considers installed versions
+    pub fn list_installed_versions_matching(
+        &self,
+        settings: &Settings,
+        query: &str,
+    ) -> Result<Vec<String>> {
+        let (query_regex, include_prereleases) = self.parse_query(settings, query);
+        let versions = self
+            .filter_prereleases(
+                settings,
+                self.list_installed_versions()?,
+                include_prereleases,
+            )?
+            .into_iter()
+            .filter(|v| query_regex.is_match(v))
+            .collect_vec();
+        Ok(versions)
+    }
+
+    fn parse_query(&self, settings: &Settings, query: &str) -> (Regex, bool) {
+        let (query, include_prereleases) = match query {
+            "latest" => ("[0-9]", self.include_prereleases(settings)),
+            "latest-prerelease" => ("[0-9]", true),
+            _ => (query, self.include_prereleases(settings)),
+        };
         let query_regex =
             Regex::new((String::from(r"^\s*") + query).as_str()).expect("error parsing regex");
+        (query_regex, include_prereleases)
+    }
+
+    /// the output of the plugin's bin/latest-stable for a query (e.g.: "18"), if it has one
+    ///
+    /// this is used instead of filtering prereleases out of the remote versions,
+    /// unless the `include_prereleases` or `prerelease_regex` settings are set for this plugin
+    pub fn latest_stable(&self, settings: &Settings, query: &str) -> Result<Option<String>> {
+        if query == "latest-prerelease"
+            || self.include_prereleases(settings)
+            || settings.prerelease_regex(&self.name).is_some()
+        {
+            return Ok(None);
+        }
+        let query_cache;
+        let (script_query, cache) = match query {
+            // same default query as asdf
+            "latest" => ("[0-9]", &self.latest_stable_cache),
+            _ => {
+                query_cache = self.latest_stable_query_cache(query);
+                (query, &query_cache)
+            }
+        };
+        let latest_stable = match settings.offline {
+            true => cache.get_or_try_init_stale(|| Ok(None))?,
+            false => {
+                cache.get_or_try_init(|| self.backend.latest_stable(settings, script_query))?
+            }
+        };
+        let latest_stable = latest_stable
+            .clone()
+            .filter(|v| self.latest_stable_matches(settings, query, v));
+        Ok(latest_stable)
+    }
+
+    /// scripts usually `grep "^$query"`, so `20.1` can print `20.19.0`. The output is only used
+    /// if it matches the query like list_versions_matching does and the query ends at a segment
+    fn latest_stable_matches(&self, settings: &Settings, query: &str, version: &str) -> bool {
+        let (query_regex, _) = self.parse_query(settings, query);
+        match query_regex.find(version) {
+            Some(_) if query == "latest" => true,
+            Some(m) => !version[m.end()..].starts_with(|c: char| c.is_ascii_digit()),
+            None => false,
+        }
+    }
+
+    /// each prefix query gets its own cache file in cache/<PLUGIN>/latest_stable/
+    fn latest_stable_query_cache(&self, query: &str) -> CacheManager<Option<String>> {
+        let filename = format!("{}.msgpack.zlib", hash_to_str(&query));
+        CacheManager::new(self.cache_path.join("latest_stable").join(filename))
+            .with_fresh_duration(fresh_duration())
+            .with_fresh_file(self.plugin_path.clone())
+            .with_fresh_file(self.plugin_path.join("bin/latest-stable"))
+    }
+
+    /// remote versions, excluding prereleases (e.g.: 1.0.0-rc1) unless include_prereleases is set
+    pub fn list_remote_versions_filtered(
+        &self,
+        settings: &Settings,
+        include_prereleases: bool,
+    ) -> Result<Vec<String>> {
         let versions = self
             .list_remote_versions(settings)?
             .iter()
-            .filter(|v| !version_regex.is_match(v))
-            .filter(|v| query_regex.is_match(v))
+            .filter(|v| !v.starts_with("Available versions:"))
             .cloned()
             .collect_vec();
-        Ok(versions)
+        self.filter_prereleases(settings, versions, include_prereleases)
+    }
+
+    fn filter_prereleases(
+        &self,
+        settings: &Settings,
+        versions: Vec<String>,
+        include_prereleases: bool,
+    ) -> Result<Vec<String>> {
+        if include_prereleases {
+            return Ok(versions);
+        }
+        let prerelease_regex = self.prerelease_regex(settings)?;
+        Ok(versions
+            .into_iter()
+            .filter(|v| !prerelease_regex.is_match(v))
+            .collect())
+    }
+
+    pub fn include_prereleases(&self, settings: &Settings) -> bool {
+        settings.include_prereleases(&self.name)
+    }
+
+    fn prerelease_regex(&self, settings: &Settings) -> Result<Regex> {
+        let regex = match settings.prerelease_regex(&self.name) {
+            Some(regex) => regex.as_str(),
+            None => match &self.manifest.prerelease_regex {
+                Some(regex) => regex.as_str(),
+                None => DEFAULT_PRERELEASE_REGEX,
+            },
+        };
+        Regex::new(regex).wrap_err_with(|| format!("invalid prerelease_regex: {regex}"))
     }
 
     pub fn list_installed_versions(&self) -> Result<Vec<String>> {
         Ok(match self.installs_path.exists() {
-            true => file::dir_subdirs(&self.installs_path)?
-                .iter()
-                .map(|v| Versioning::new(v).unwrap_or_default())
-                .sorted()
-                .map(|v| v.to_string())
-                .collect(),
+            true => sort_versions(file::dir_subdirs(&self.installs_path)?),
             false => vec![],
         })
     }
@@ -235,12 +483,169 @@ impl Plugin {
     pub fn clear_remote_version_cache(&self) -> Result<()> {
         self.remote_version_cache.clear()
     }
+
+    /// the shas this plugin has been at, oldest first
+    pub fn history(&self) -> Result<Vec<HistoryEntry>> {
+        history::load(&self.name)
+    }
+
+    /// checks out a previous sha of the plugin, either `to` (which may be abbreviated)
+    /// or the sha before the current one in the plugin's history
+    ///
+    /// returns the previous and new sha
+    pub fn rollback(&self, to: Option<&str>) -> Result<(String, String)> {
+        let git = Git::new(self.plugin_path.to_path_buf());
+        if self.plugin_path.is_symlink() || !git.is_repo() {
+            return Err(eyre!("plugin {} is not a git repository", self.name));
+        }
+        let current = git.current_sha()?;
+        let history = self.history()?;
+        let target = match to {
+            Some(to) => history
+                .iter()
+                .rev()
+                .find(|e| e.sha.starts_with(to))
+                .map(|e| e.sha.clone())
+                .unwrap_or_else(|| to.to_string()),
+            None => history
+                .iter()
+                .rev()
+                .map(|e| e.sha.clone())
+                .find(|sha| sha != &current)
+                .ok_or_else(|| eyre!("no previous version of {} in its history", self.name))?,
+        };
+        let post = git.checkout(&target)?;
+        if current != post {
+            self.clear_cache()?;
+        }
+        // don't let autoupdate immediately undo the rollback
+        self.touch_last_autoupdate()?;
+        self.record_history(&current);
+        self.record_history(&post);
+        Ok((current, post))
+    }
+
+    fn record_history(&self, sha: &str) {
+        if let Err(err) = history::record(&self.name, sha) {
+            warn!(
+                "failed to record history for plugin {}: {:#}",
+                self.name, err
+            );
+        }
+    }
+
+    /// updates the plugin in a background process if it has not been checked for updates within
+    /// `plugin_autoupdate_last_check_duration`. Setting that to 0 disables this.
+    ///
+    /// this is called after a plugin is used so the update can't change it while it is running
+    pub fn autoupdate(&self, config: &Config) {
+        if !self.needs_autoupdate(config) {
+            return;
+        }
+        if let Err(err) = self
+            .touch_last_autoupdate()
+            .and_then(|_| self.spawn_autoupdate())
+        {
+            debug!("failed to autoupdate plugin {}: {:#}", self.name, err);
+        }
+    }
+
+    /// plugins pinned to a git ref or whose sha is recorded in a lockfile are not autoupdated
+    /// since moving them would undo the pin or make `rtx install --frozen` fail
+    fn needs_autoupdate(&self, config: &Config) -> bool {
+        let settings = &config.settings;
+        let duration = settings.plugin_autoupdate_last_check_duration;
+        if duration.is_zero() || settings.offline || self.plugin_path.is_symlink() {
+            return false;
+        }
+        if !Git::new(self.plugin_path.clone()).is_repo()
+            || settings.plugin_ref(&self.name).is_some()
+            || lockfile::locks_plugin_sha(config, &self.name)
+        {
+            return false;
+        }
+        let last_autoupdate = fs::metadata(self.last_autoupdate_path()).and_then(|m| m.modified());
+        match last_autoupdate {
+            Ok(mtime) => mtime.elapsed().unwrap_or_default() >= duration,
+            Err(_) => true,
+        }
+    }
+
+    fn spawn_autoupdate(&self) -> Result<()> {
+        debug!("autoupdating plugin {} in the background", self.name);
+        cmd(&*env::RTX_EXE, ["plugins", "update", &self.name])
+            .stdin_null()
+            .stdout_null()
+            .stderr_null()
+            .unchecked()
+            .start()?;
+        Ok(())
+    }
+
+    /// records that the plugin was just updated, see `autoupdate`
+    fn touch_last_autoupdate(&self) -> Result<()> {
+        let path = self.last_autoupdate_path();
+        match path.exists() {
+            true => set_file_mtime(&path, FileTime::now())?,
+            false => {
+                fs::create_dir_all(&self.cache_path)?;
+                fs::File::create(&path)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn last_autoupdate_path(&self) -> PathBuf {
+        self.cache_path.join("last_autoupdate")
+    }
+
+    /// clears the cached output of the plugin's scripts, e.g.: after the plugin is updated
+    fn clear_cache(&self) -> Result<()> {
+        self.remote_version_cache.clear()?;
+        self.alias_cache.clear()?;
+        self.legacy_filename_cache.clear()?;
+        self.latest_stable_cache.clear()?;
+        let query_caches = self.cache_path.join("latest_stable");
+        if query_caches.exists() {
+            fs::remove_dir_all(query_caches)?;
+        }
+        Ok(())
+    }
+
+    /// when each of the plugin's caches was last written and whether it is still fresh,
+    /// caches that are not used because `rtx-plugin.toml` provides the values are left out
+    pub fn cache_status(&self) -> Vec<(&'static str, Option<(SystemTime, bool)>)> {
+        let mut status = vec![("remote versions", self.remote_version_cache.status())];
+        if self.manifest.aliases.is_none() {
+            status.push(("aliases", self.alias_cache.status()));
+        }
+        if self.manifest.legacy_filenames.is_none() {
+            status.push(("legacy filenames", self.legacy_filename_cache.status()));
+        }
+        status.push(("latest stable", self.latest_stable_cache.status()));
+        status
+    }
+
+    /// documentation from the plugin's help.* scripts as `(topic, text)`
+    pub fn help(&self) -> Result<Vec<(String, String)>> {
+        self.backend.help()
+    }
+    /// when offline, uses the cached remote versions even if stale
+    /// or falls back to the installed versions if there is no cache
     pub fn list_remote_versions(&self, settings: &Settings) -> Result<&Vec<String>> {
+        if settings.offline {
+            return self
+                .remote_version_cache
+                .get_or_try_init_stale(|| self.list_installed_versions());
+        }
         self.remote_version_cache
             .get_or_try_init(|| self.fetch_remote_versions(settings))
     }
 
     pub fn get_aliases(&self, settings: &Settings) -> Result<IndexMap<String, String>> {
+        if let Some(aliases) = &self.manifest.aliases {
+            return Ok(aliases.clone().into_iter().collect());
+        }
         let aliases = self
             .alias_cache
             .get_or_try_init(|| self.fetch_aliases(settings))?
@@ -251,6 +656,9 @@ impl Plugin {
     }
 
     pub fn legacy_filenames(&self, settings: &Settings) -> Result<&Vec<String>> {
+        if let Some(legacy_filenames) = &self.manifest.legacy_filenames {
+            return Ok(legacy_filenames);
+        }
         self.legacy_filename_cache
             .get_or_try_init(|| self.fetch_legacy_filenames(settings))
     }
@@ -295,82 +703,31 @@ impl Plugin {
     }
 
     fn fetch_remote_versions(&self, settings: &Settings) -> Result<Vec<String>> {
-        let result = self
-            .script_man
-            .cmd(Script::ListAll)
-            .stdout_capture()
-            .stderr_capture()
-            .unchecked()
-            .run()
-            .with_context(|| {
-                let script = self.script_man.get_script_path(&Script::ListAll);
-                format!("failed to run {}", script.display())
-            })?;
-        let stdout = String::from_utf8(result.stdout).unwrap();
-        let stderr = String::from_utf8(result.stderr).unwrap().trim().to_string();
-
-        let display_stderr = || {
-            if !stderr.is_empty() {
-                eprintln!("{stderr}");
-            }
-        };
-        if !result.status.success() {
-            display_stderr();
-            return Err(eyre!(
-                "error running {}: exited with code {}",
-                Script::ListAll,
-                result.status.code().unwrap_or_default()
-            ))?;
-        } else if settings.verbose {
-            display_stderr();
-        }
+        Ok(sort_versions(self.backend.list_remote_versions(settings)?))
+    }
 
-        Ok(stdout.split_whitespace().map(|v| v.into()).collect())
+    fn fetch_aliases(&self, settings: &Settings) -> Result<Vec<(String, String)>> {
+        self.backend.list_aliases(settings)
     }
 
     fn fetch_legacy_filenames(&self, settings: &Settings) -> Result<Vec<String>> {
-        if !self.has_list_legacy_filenames_script() {
-            return Ok(vec![]);
-        }
-        Ok(self
-            .script_man
-            .read(Script::ListLegacyFilenames, settings.verbose)?
-            .split_whitespace()
-            .map(|v| v.into())
-            .collect())
+        self.backend.list_legacy_filenames(settings)
+    }
+
+    /// for running the lifecycle scripts of plugins installed with `rtx plugins install`
+    fn script_man(&self) -> ScriptManager {
+        ScriptManager::new(self.plugin_path.clone())
     }
 
     fn has_list_all_script(&self) -> bool {
-        self.script_man.script_exists(&Script::ListAll)
+        self.script_man().script_exists(&Script::ListAll)
     }
     fn has_list_alias_script(&self) -> bool {
-        self.script_man.script_exists(&Script::ListAliases)
+        self.script_man().script_exists(&Script::ListAliases)
     }
     fn has_list_legacy_filenames_script(&self) -> bool {
-        self.script_man.script_exists(&Script::ListLegacyFilenames)
-    }
-    fn fetch_aliases(&self, settings: &Settings) -> Result<Vec<(String, String)>> {
-        if !self.has_list_alias_script() {
-            return Ok(vec![]);
-        }
-        let stdout = self
-            .script_man
-            .read(Script::ListAliases, settings.verbose)?;
-        let aliases = stdout
-            .lines()
-            .filter_map(|line| {
-                let mut parts = line.split_whitespace().collect_vec();
-                if parts.len() != 2 {
-                    if !parts.is_empty() {
-                        trace!("invalid alias line: {}", line);
-                    }
-                    return None;
-                }
-                Some((parts.remove(0).into(), parts.remove(0).into()))
-            })
-            .collect();
-
-        Ok(aliases)
+        self.script_man()
+            .script_exists(&Script::ListLegacyFilenames)
     }
 
     pub fn parse_legacy_file(&self, legacy_file: &Path, settings: &Settings) -> Result<String> {
@@ -378,13 +735,7 @@ impl Plugin {
             return Ok(cached);
         }
         trace!("parsing legacy file: {}", legacy_file.to_string_lossy());
-        let script = ParseLegacyFile(legacy_file.to_string_lossy().into());
-        let legacy_version = match self.script_man.script_exists(&script) {
-            true => self.script_man.read(script, settings.verbose)?,
-            false => fs::read_to_string(legacy_file)?,
-        }
-        .trim()
-        .to_string();
+        let legacy_version = self.backend.parse_legacy_file(legacy_file, settings)?;
 
         self.write_legacy_cache(legacy_file, &legacy_version)?;
         Ok(legacy_version)
@@ -420,14 +771,143 @@ impl PartialEq for Plugin {
     }
 }
 
+/// sorts versions semantically (e.g.: 1.10.0 comes after 1.9.0) regardless of
+/// the order the plugin printed them in
+/// versions that can't be parsed are kept at the beginning in lexicographic order
+fn sort_versions<I>(versions: I) -> Vec<String>
+where
+    I: IntoIterator<Item = String>,
+{
+    versions
+        .into_iter()
+        .map(|v| (Versioning::new(&v), v))
+        .sorted()
+        .map(|(_, v)| v)
+        .collect()
+}
+
+/// how long cached script output is used before running the script again,
+/// forever if RTX_PREFER_STALE is set
+fn fresh_duration() -> Option<Duration> {
+    if *RTX_PREFER_STALE {
+        None
+    } else {
+        Some(Duration::from_secs(60 * 60 * 24))
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use indoc::indoc;
     use pretty_assertions::assert_str_eq;
 
     use crate::assert_cli;
+    use crate::lockfile::Lockfile;
+    use crate::runtimes::RuntimeVersion;
+    use crate::test::run_git;
+    use crate::toolset::ToolVersionOptions;
 
     use super::*;
 
+    #[derive(Debug)]
+    struct TestBackend;
+
+    impl Backend for TestBackend {
+        fn list_remote_versions(&self, _settings: &Settings) -> Result<Vec<String>> {
+            Ok(vec!["1.10.0".into(), "1.9.0".into(), "2.0.0-rc1".into()])
+        }
+
+        fn list_aliases(&self, _settings: &Settings) -> Result<Vec<(String, String)>> {
+            Ok(vec![("lts".into(), "1.9".into())])
+        }
+
+        fn install_version(
+            &self,
+            _settings: &Settings,
+            rtv: &RuntimeVersion,
+            _pr: &ProgressReport,
+        ) -> Result<()> {
+            let bin_path = rtv.install_path.join("bin");
+            fs::create_dir_all(&bin_path)?;
+            fs::write(bin_path.join("core-test"), rtv.version.as_str())?;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_core_plugin() {
+        let config = Config::load().unwrap();
+        let plugin = Plugin::with_backend(&"core-test".into(), Arc::new(TestBackend), true);
+        plugin.clear_cache().unwrap();
+        assert!(plugin.is_installed());
+        let latest = plugin.latest_version(&config.settings, "latest").unwrap();
+        assert_eq!(latest.unwrap(), "1.10.0");
+        let aliases = plugin.get_aliases(&config.settings).unwrap();
+        assert_eq!(aliases["lts"], "1.9");
+        let err = plugin.uninstall(&config.settings).unwrap_err();
+        assert_str_eq!(
+            err.to_string(),
+            "core-test is a core plugin and can't be uninstalled"
+        );
+
+        let rtv = RuntimeVersion::new(
+            Arc::new(plugin),
+            InstallType::Version("1.10.0".into()),
+            ToolVersionOptions::default(),
+        );
+        rtv.install(&config, ProgressReport::new(true)).unwrap();
+        let bin = rtv.which("core-test").unwrap().unwrap();
+        assert_str_eq!(fs::read_to_string(bin).unwrap(), "1.10.0");
+        rtv.uninstall(&config.settings).unwrap();
+        assert!(!rtv.is_installed());
+    }
+
+    #[test]
+    fn test_manifest_is_preferred_over_backend() {
+        let config = Config::load().unwrap();
+        let name = PluginName::from("manifest-test");
+        let mut plugin = Plugin::with_backend(&name, Arc::new(TestBackend), true);
+        plugin.clear_cache().unwrap();
+        plugin.manifest = toml::from_str(indoc! {r#"
+            legacy_filenames = [".manifest-test-version"]
+            bin_paths = ["libexec"]
+            prerelease_regex = '^1\.10\.'
+
+            [aliases]
+            stable = "1.10"
+
+            [exec_env]
+            MANIFEST_TEST_HOME = "$ASDF_INSTALL_PATH"
+        "#})
+        .unwrap();
+        let aliases = plugin.get_aliases(&config.settings).unwrap();
+        assert_eq!(
+            aliases.into_iter().collect_vec(),
+            [("stable".into(), "1.10".into())]
+        );
+        let legacy_filenames = plugin.legacy_filenames(&config.settings).unwrap();
+        assert_eq!(legacy_filenames, &vec![".manifest-test-version"]);
+        let latest = plugin.latest_version(&config.settings, "latest").unwrap();
+        assert_eq!(latest.unwrap(), "2.0.0-rc1");
+
+        let rtv = RuntimeVersion::new(
+            Arc::new(plugin),
+            InstallType::Version("1.10.0".into()),
+            ToolVersionOptions::default(),
+        );
+        rtv.install(&config, ProgressReport::new(true)).unwrap();
+        assert_eq!(
+            rtv.list_bin_paths().unwrap(),
+            [rtv.install_path.join("libexec")]
+        );
+        let exec_env = rtv.exec_env().unwrap();
+        assert_eq!(
+            exec_env["MANIFEST_TEST_HOME"],
+            rtv.install_path.to_string_lossy()
+        );
+        rtv.uninstall(&config.settings).unwrap();
+    }
+
     #[test]
     fn test_exact_match() {
         assert_cli!("plugin", "add", "tiny");
@@ -436,4 +916,164 @@ mod tests {
         let version = plugin.latest_version(&settings, "1.0.0").unwrap().unwrap();
         assert_str_eq!(version, "1.0.0");
     }
+
+    #[test]
+    fn test_prerelease_regex() {
+        let mut settings = Settings {
+            prerelease_regex: Some(r"^2\.".into()),
+            ..Settings::default()
+        };
+        let plugin = Plugin::new(&PluginName::from("dummy"));
+        let version = plugin.latest_version(&settings, "latest").unwrap();
+        assert_eq!(version.unwrap(), "1.1.0");
+        let version = plugin
+            .latest_version(&settings, "latest-prerelease")
+            .unwrap();
+        assert_eq!(version.unwrap(), "2.0.0");
+        settings.include_prereleases = true;
+        let version = plugin.latest_version(&settings, "latest").unwrap();
+        assert_eq!(version.unwrap(), "2.0.0");
+    }
+
+    #[test]
+    fn test_latest_stable() {
+        let settings = Settings::default();
+        let plugin = Plugin::new(&PluginName::from("dummy"));
+        plugin.clear_cache().unwrap();
+        let version = plugin.latest_stable(&settings, "latest").unwrap();
+        assert_eq!(version.unwrap(), "2.0.0");
+        assert!(matches!(
+            plugin.latest_stable_cache.status(),
+            Some((_, true))
+        ));
+        let version = plugin.latest_stable(&settings, "1").unwrap();
+        assert_eq!(version.unwrap(), "1.1.0");
+        assert!(plugin.latest_stable_query_cache("1").status().is_some());
+        plugin.clear_cache().unwrap();
+        assert!(plugin.latest_stable_query_cache("1").status().is_none());
+
+        let plugin = Plugin::new(&PluginName::from("tiny"));
+        assert_eq!(plugin.latest_stable(&settings, "latest").unwrap(), None);
+    }
+
+    #[test]
+    fn test_latest_version_prefers_latest_stable() {
+        #[derive(Debug)]
+        struct LatestStableBackend;
+
+        impl Backend for LatestStableBackend {
+            fn list_remote_versions(&self, _settings: &Settings) -> Result<Vec<String>> {
+                Ok(vec![
+                    "1.0.0".into(),
+                    "1.1.0".into(),
+                    "1.10.0".into(),
+                    "2.0.0".into(),
+                ])
+            }
+
+            fn latest_stable(&self, _settings: &Settings, query: &str) -> Result<Option<String>> {
+                let v = match query {
+                    "[0-9]" | "1" => "1.1.0",
+                    "1.1" => "1.10.0",
+                    _ => "2.0.0",
+                };
+                Ok(Some(v.into()))
+            }
+
+            fn install_version(
+                &self,
+                _settings: &Settings,
+                _rtv: &RuntimeVersion,
+                _pr: &ProgressReport,
+            ) -> Result<()> {
+                Ok(())
+            }
+        }
+
+        let settings = Settings::default();
+        let name = PluginName::from("latest-stable-test");
+        let plugin = Plugin::with_backend(&name, Arc::new(LatestStableBackend), true);
+        plugin.clear_cache().unwrap();
+        let latest = |query| plugin.latest_version(&settings, query).unwrap().unwrap();
+        assert_str_eq!(latest("latest"), "1.1.0");
+        assert_str_eq!(latest("1"), "1.1.0");
+        assert_str_eq!(latest("latest-prerelease"), "2.0.0");
+        // exact versions don't run latest-stable
+        assert_str_eq!(latest("1.0.0"), "1.0.0");
+        assert!(plugin.latest_stable_query_cache("1.0.0").status().is_none());
+        // output that doesn't match the query is ignored
+        assert_str_eq!(latest("1.0"), "1.0.0");
+        assert_eq!(plugin.latest_stable(&settings, "1.1").unwrap(), None);
+        plugin.clear_cache().unwrap();
+    }
+
+    #[test]
+    fn test_autoupdate_skips_non_git_plugins() {
+        let plugin = Plugin::new(&PluginName::from("dummy"));
+        plugin.autoupdate(&Config::load().unwrap());
+        assert!(!plugin.last_autoupdate_path().exists());
+    }
+
+    #[test]
+    fn test_autoupdate() {
+        let name = PluginName::from("autoupdate-test");
+        let plugin = Plugin::new(&name);
+        fs::create_dir_all(&plugin.plugin_path).unwrap();
+        run_git(&plugin.plugin_path, &["init", "-q"]);
+        let mut config = Config::load().unwrap();
+        let make_stale = || {
+            plugin.touch_last_autoupdate().unwrap();
+            let path = plugin.last_autoupdate_path();
+            set_file_mtime(path, FileTime::from_unix_time(0, 0)).unwrap();
+        };
+
+        make_stale();
+        assert!(plugin.needs_autoupdate(&config));
+        plugin.touch_last_autoupdate().unwrap();
+        assert!(!plugin.needs_autoupdate(&config));
+
+        make_stale();
+        let url = "https://example.com/autoupdate-test.git#v1.0.0";
+        config.settings.plugins.insert(name.clone(), url.into());
+        assert!(!plugin.needs_autoupdate(&config));
+        config.settings.plugins.remove(&name);
+
+        let dir = tempfile::tempdir().unwrap();
+        let cf_path = dir
+            .path()
+            .join(env::RTX_DEFAULT_TOOL_VERSIONS_FILENAME.as_str());
+        let mut lockfile = Lockfile::load(&cf_path).unwrap();
+        lockfile.plugins.entry(name.clone()).or_default().plugin_sha = Some("abc123".into());
+        lockfile.save().unwrap();
+        config.config_files.push(cf_path);
+        assert!(!plugin.needs_autoupdate(&config));
+
+        fs::remove_dir_all(&plugin.plugin_path).unwrap();
+        fs::remove_dir_all(&plugin.cache_path).unwrap();
+    }
+
+    #[test]
+    fn test_sort_versions() {
+        let versions = [
+            "1.10.0",
+            "1.9.0",
+            "2.0.0-rc1",
+            "1.2",
+            "2.0.0",
+            "nightly",
+            "1.9.0a1",
+        ];
+        assert_eq!(
+            sort_versions(versions.map(String::from)),
+            vec![
+                "nightly",
+                "1.2",
+                "1.9.0",
+                "1.9.0a1",
+                "1.10.0",
+                "2.0.0-rc1",
+                "2.0.0"
+            ]
+        );
+    }
 }
@@ -0,0 +1,283 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use color_eyre::eyre::{eyre, Result, WrapErr};
+
+use crate::cmd;
+use crate::config::Settings;
+use crate::env_diff::{EnvDiff, EnvDiffOperation};
+use crate::plugins::backend::parse_aliases;
+use crate::plugins::{Backend, Script, ScriptManager};
+use crate::runtimes::RuntimeVersion;
+use crate::ui::progress_report::ProgressReport;
+
+/// an asdf plugin, implemented with the shell scripts in its bin/ directory
+///
+/// see https://asdf-vm.com/plugins/create.html
+#[derive(Debug, Clone)]
+pub struct ScriptBackend {
+    plugin_path: PathBuf,
+    script_man: ScriptManager,
+}
+
+impl ScriptBackend {
+    pub fn new(plugin_path: PathBuf) -> Self {
+        Self {
+            script_man: ScriptManager::new(plugin_path.clone()),
+            plugin_path,
+        }
+    }
+
+    /// a script manager with the env vars asdf passes to scripts that act on a version
+    fn version_script_man(&self, rtv: &RuntimeVersion) -> ScriptManager {
+        ScriptManager::new(self.plugin_path.clone()).with_envs(rtv.install_env())
+    }
+}
+
+impl Backend for ScriptBackend {
+    fn list_remote_versions(&self, settings: &Settings) -> Result<Vec<String>> {
+        let result = self
+            .script_man
+            .cmd(Script::ListAll)
+            .stdout_capture()
+            .stderr_capture()
+            .unchecked()
+            .run()
+            .with_context(|| {
+                let script = self.script_man.get_script_path(&Script::ListAll);
+                format!("failed to run {}", script.display())
+            })?;
+        let stdout = String::from_utf8(result.stdout).unwrap();
+        let stderr = String::from_utf8(result.stderr).unwrap().trim().to_string();
+
+        let display_stderr = || {
+            if !stderr.is_empty() {
+                eprintln!("{stderr}");
+            }
+        };
+        if !result.status.success() {
+            display_stderr();
+            return Err(eyre!(
+                "error running {}: exited with code {}",
+                Script::ListAll,
+                result.status.code().unwrap_or_default()
+            ))?;
+        } else if settings.verbose {
+            display_stderr();
+        }
+
+        Ok(stdout.split_whitespace().map(|v| v.into()).collect())
+    }
+
+    fn latest_stable(&self, settings: &Settings, query: &str) -> Result<Option<String>> {
+        let script = Script::LatestStable(query.into());
+        if !self.script_man.script_exists(&script) {
+            return Ok(None);
+        }
+        let stdout = self.script_man.read(script, settings.verbose)?;
+        let version = stdout.trim();
+        Ok((!version.is_empty()).then(|| version.into()))
+    }
+
+    fn list_aliases(&self, settings: &Settings) -> Result<Vec<(String, String)>> {
+        if !self.script_man.script_exists(&Script::ListAliases) {
+            return Ok(vec![]);
+        }
+        let stdout = self
+            .script_man
+            .read(Script::ListAliases, settings.verbose)?;
+        Ok(parse_aliases(&stdout))
+    }
+
+    fn list_legacy_filenames(&self, settings: &Settings) -> Result<Vec<String>> {
+        if !self.script_man.script_exists(&Script::ListLegacyFilenames) {
+            return Ok(vec![]);
+        }
+        Ok(self
+            .script_man
+            .read(Script::ListLegacyFilenames, settings.verbose)?
+            .split_whitespace()
+            .map(|v| v.into())
+            .collect())
+    }
+
+    fn parse_legacy_file(&self, legacy_file: &Path, settings: &Settings) -> Result<String> {
+        let script = Script::ParseLegacyFile(legacy_file.to_string_lossy().into());
+        let legacy_version = match self.script_man.script_exists(&script) {
+            true => self.script_man.read(script, settings.verbose)?,
+            false => fs::read_to_string(legacy_file)?,
+        };
+        Ok(legacy_version.trim().to_string())
+    }
+
+    fn install_version(
+        &self,
+        settings: &Settings,
+        rtv: &RuntimeVersion,
+        pr: &ProgressReport,
+    ) -> Result<()> {
+        let script_man = self.version_script_man(rtv);
+        let download = Script::Download(rtv.install_type.clone());
+        let install = Script::Install(rtv.install_type.clone());
+        let run_script = |script| {
+            let on_error = |output: String| {
+                if !settings.verbose && !output.trim().is_empty() {
+                    pr.println(output);
+                }
+            };
+            let on_output = |line: &str| {
+                if !line.trim().is_empty() {
+                    pr.set_message(line.into());
+                }
+            };
+            let plugin_name = &script_man.plugin_name;
+            cmd::run_by_line(plugin_name, script_man.cmd(script), on_error, on_output)
+        };
+
+        if script_man.script_exists(&download) {
+            pr.set_message("downloading".into());
+            run_script(download)?;
+        }
+        pr.set_message("installing".into());
+        run_script(install)
+    }
+
+    fn uninstall_version(&self, rtv: &RuntimeVersion) -> Result<()> {
+        let script_man = self.version_script_man(rtv);
+        if script_man.script_exists(&Script::Uninstall) {
+            script_man.run(Script::Uninstall)?;
+        }
+        Ok(())
+    }
+
+    fn list_bin_paths(&self, rtv: &RuntimeVersion) -> Result<Vec<String>> {
+        let script_man = self.version_script_man(rtv);
+        if !script_man.script_exists(&Script::ListBinPaths) {
+            return Ok(vec!["bin".into()]);
+        }
+        let output = script_man.cmd(Script::ListBinPaths).read()?;
+        Ok(output.split_whitespace().map(|e| e.into()).collect())
+    }
+
+    fn exec_path(&self, rtv: &RuntimeVersion, bin_name: &str, path: &str) -> Result<String> {
+        let install_path = rtv.install_path.to_string_lossy().to_string();
+        let script = Script::ExecPath(install_path, bin_name.into(), path.into());
+        let script_man = self.version_script_man(rtv);
+        if !script_man.script_exists(&script) {
+            return Ok(path.into());
+        }
+        let output = script_man.cmd(script).read()?;
+        Ok(output.trim().into())
+    }
+
+    fn exec_env(&self, rtv: &RuntimeVersion) -> Result<HashMap<String, String>> {
+        let script = self.plugin_path.join("bin/exec-env");
+        if !script.exists() {
+            return Ok(HashMap::new());
+        }
+        let script_man = self.version_script_man(rtv);
+        let ed = EnvDiff::from_bash_script(&script, &script_man.env)?;
+        let env = ed
+            .to_patches()
+            .into_iter()
+            .filter_map(|p| match p {
+                EnvDiffOperation::Add(key, value) => Some((key, value)),
+                EnvDiffOperation::Change(key, value) => Some((key, value)),
+                _ => None,
+            })
+            .collect();
+        Ok(env)
+    }
+
+    fn help(&self) -> Result<Vec<(String, String)>> {
+        let scripts = [
+            ("overview", Script::HelpOverview),
+            ("deps", Script::HelpDeps),
+            ("config", Script::HelpConfig),
+            ("links", Script::HelpLinks),
+        ];
+        let mut help = vec![];
+        for (topic, script) in scripts {
+            if self.script_man.script_exists(&script) {
+                let text = self.script_man.read(script, false)?;
+                help.push((topic.into(), text.trim_end().into()));
+            }
+        }
+        Ok(help)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::os::unix::fs::PermissionsExt;
+    use std::sync::Arc;
+
+    use crate::plugins::{InstallType, Plugin};
+    use crate::toolset::ToolVersionOptions;
+
+    use super::*;
+
+    fn write_script(plugin_path: &Path, name: &str, body: &str) {
+        let path = plugin_path.join("bin").join(name);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, format!("#!/usr/bin/env bash\n{body}\n")).unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+    }
+
+    #[test]
+    fn test_latest_stable_and_help() {
+        let dir = tempfile::tempdir().unwrap();
+        let plugin_path = dir.path().join("script-test");
+        let settings = Settings::default();
+        let backend = ScriptBackend::new(plugin_path.clone());
+        assert_eq!(backend.latest_stable(&settings, "[0-9]").unwrap(), None);
+        assert!(backend.help().unwrap().is_empty());
+
+        write_script(&plugin_path, "latest-stable", r#"echo "1.2.3-$1""#);
+        write_script(&plugin_path, "help.overview", "echo overview");
+        write_script(&plugin_path, "help.links", "echo https://example.com");
+        let latest = backend.latest_stable(&settings, "1").unwrap();
+        assert_eq!(latest.unwrap(), "1.2.3-1");
+        assert_eq!(
+            backend.help().unwrap(),
+            vec![
+                ("overview".into(), "overview".into()),
+                ("links".into(), "https://example.com".into()),
+            ]
+        );
+
+        write_script(&plugin_path, "latest-stable", "echo");
+        assert_eq!(backend.latest_stable(&settings, "[0-9]").unwrap(), None);
+    }
+
+    #[test]
+    fn test_exec_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let plugin_path = dir.path().join("exec-path-test");
+        let install_path = dir.path().join("install");
+        for path in ["bin/foo", "bin/bar", "bin/baz", "libexec/foo"] {
+            let path = install_path.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "").unwrap();
+        }
+        write_script(
+            &plugin_path,
+            "exec-path",
+            r#"case "$2" in foo) echo "libexec/$2" ;; baz) ;; *) echo "$3" ;; esac"#,
+        );
+        let backend = Arc::new(ScriptBackend::new(plugin_path));
+        let plugin = Plugin::with_backend(&"exec-path-test".into(), backend, false);
+        let rtv = RuntimeVersion::new(
+            Arc::new(plugin),
+            InstallType::Path(install_path.clone()),
+            ToolVersionOptions::default(),
+        );
+        let which = |bin_name| rtv.which(bin_name).unwrap();
+        assert_eq!(which("foo"), Some(install_path.join("libexec/foo")));
+        assert_eq!(which("bar"), Some(install_path.join("bin/bar")));
+        // no output falls back to the executable in the bin path
+        assert_eq!(which("baz"), Some(install_path.join("bin/baz")));
+        assert_eq!(which("qux"), None);
+    }
+}
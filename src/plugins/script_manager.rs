@@ -1,9 +1,7 @@
 use std::fmt;
 use std::fmt::{Display, Formatter};
-use std::io::{BufRead, BufReader};
 use std::path::PathBuf;
 use std::process::Output;
-use std::sync::Arc;
 
 use color_eyre::eyre::{Context, Result};
 use duct::Expression;
@@ -24,16 +22,19 @@ pub struct ScriptManager {
 
 #[derive(Debug, Clone)]
 pub enum Script {
-    // PreInstall,
-    // PostInstall,
-    // PreUninstall,
-    // PostUninstall,
-
     // Plugin
     ListAll,
     ListLegacyFilenames,
     ListAliases,
     ParseLegacyFile(String),
+    LatestStable(String),
+    HelpOverview,
+    HelpDeps,
+    HelpConfig,
+    HelpLinks,
+    PostPluginAdd,
+    PostPluginUpdate,
+    PrePluginRemove,
 
     // RuntimeVersion
     Download(InstallType),
@@ -41,6 +42,8 @@ pub enum Script {
     Uninstall,
     ListBinPaths,
     // ExecEnv,
+    /// the install path, the command, and the path to it relative to the install path
+    ExecPath(String, String, String),
 }
 
 impl fmt::Display for Script {
@@ -51,12 +54,21 @@ impl fmt::Display for Script {
             Script::ListLegacyFilenames => write!(f, "list-legacy-filenames"),
             Script::ListAliases => write!(f, "list-aliases"),
             Script::ParseLegacyFile(_) => write!(f, "parse-legacy-file"),
+            Script::LatestStable(_) => write!(f, "latest-stable"),
+            Script::HelpOverview => write!(f, "help.overview"),
+            Script::HelpDeps => write!(f, "help.deps"),
+            Script::HelpConfig => write!(f, "help.config"),
+            Script::HelpLinks => write!(f, "help.links"),
+            Script::PostPluginAdd => write!(f, "post-plugin-add"),
+            Script::PostPluginUpdate => write!(f, "post-plugin-update"),
+            Script::PrePluginRemove => write!(f, "pre-plugin-remove"),
 
             // RuntimeVersion
             Script::Install(_) => write!(f, "install"),
             Script::Uninstall => write!(f, "uninstall"),
             Script::ListBinPaths => write!(f, "list-bin-paths"),
             // Script::ExecEnv => write!(f, "exec-env"),
+            Script::ExecPath(..) => write!(f, "exec-path"),
             Script::Download(_) => write!(f, "download"),
         }
     }
@@ -123,6 +135,10 @@ impl ScriptManager {
     pub fn cmd(&self, script: Script) -> Expression {
         let args = match &script {
             Script::ParseLegacyFile(filename) => vec![filename.clone()],
+            Script::LatestStable(query) => vec![query.clone()],
+            Script::ExecPath(install_path, cmd, path) => {
+                vec![install_path.clone(), cmd.clone(), path.clone()]
+            }
             _ => vec![],
         };
         let script_path = self.get_script_path(&script);
@@ -154,34 +170,4 @@ impl ScriptManager {
         cmd.read()
             .with_context(|| ScriptFailed(self.plugin_name.clone(), None))
     }
-
-    pub fn run_by_line<F1, F2>(&self, script: Script, on_error: F1, on_output: F2) -> Result<()>
-    where
-        F1: Fn(String),
-        F2: Fn(&str),
-    {
-        let reader = self.cmd(script).stderr_to_stdout().unchecked().reader()?;
-        let reader = Arc::new(reader);
-        let mut output = vec![];
-        for line in BufReader::new(&*reader).lines() {
-            let line = line.unwrap();
-            on_output(&line);
-            output.push(line);
-        }
-
-        match reader.try_wait() {
-            Err(err) => {
-                on_error(output.join("\n"));
-                Err(err)?
-            }
-            Ok(out) => match out.unwrap().status.success() {
-                true => Ok(()),
-                false => {
-                    on_error(output.join("\n"));
-                    let err = ScriptFailed(self.plugin_name.clone(), Some(out.unwrap().status));
-                    Err(err)?
-                }
-            },
-        }
-    }
 }
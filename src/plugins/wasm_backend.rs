@@ -0,0 +1,385 @@
+use std::collections::HashMap;
+use std::fs;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+
+use color_eyre::eyre::{eyre, Result, WrapErr};
+use indexmap::IndexMap;
+use itertools::Itertools;
+use once_cell::sync::{Lazy, OnceCell};
+use wasmtime::{Engine, Linker, Module, Store};
+use wasmtime_wasi::pipe::{MemoryInputPipe, MemoryOutputPipe};
+use wasmtime_wasi::preview1::{add_to_linker_sync, WasiP1Ctx};
+use wasmtime_wasi::{DirPerms, FilePerms, I32Exit, WasiCtxBuilder};
+
+use crate::config::Settings;
+use crate::plugins::backend::parse_aliases;
+use crate::plugins::{Backend, PluginName, Script};
+use crate::runtimes::RuntimeVersion;
+use crate::ui::progress_report::ProgressReport;
+
+/// a plugin directory with this file in it is loaded as a wasm plugin instead of a script plugin
+pub const MODULE_FILENAME: &str = "plugin.wasm";
+
+/// output beyond this makes the call fail rather than being buffered
+const MAX_OUTPUT: usize = 16 * 1024 * 1024;
+
+static ENGINE: Lazy<Engine> = Lazy::new(Engine::default);
+
+/// a plugin compiled to a WASI module (`plugin.wasm`)
+///
+/// the module exports a function for each script an asdf plugin would have, e.g.: `list-all` or
+/// `install`. Each call gets a fresh instance that can only see what that operation needs:
+///
+/// - its arguments, the ASDF_* env vars, and `install_env`, not the rest of the environment
+/// - `ASDF_DOWNLOAD_PATH` and `ASDF_INSTALL_PATH`, and only for the operations that act on a version
+/// - no network access and no way to run other programs
+///
+/// instead of fetching files itself, `download` prints `<url> [filename]` lines and rtx downloads
+/// them into `ASDF_DOWNLOAD_PATH` before calling `install`
+#[derive(Debug)]
+pub struct WasmBackend {
+    name: PluginName,
+    module_path: PathBuf,
+    module: OnceCell<Module>,
+}
+
+/// what a single call into the module is allowed to access
+#[derive(Default)]
+struct Capabilities<'a> {
+    args: Vec<String>,
+    env: IndexMap<String, String>,
+    stdin: String,
+    read_dirs: Vec<&'a Path>,
+    write_dirs: Vec<&'a Path>,
+}
+
+impl WasmBackend {
+    pub fn new(name: &PluginName, plugin_path: &Path) -> Self {
+        Self {
+            name: name.clone(),
+            module_path: plugin_path.join(MODULE_FILENAME),
+            module: OnceCell::new(),
+        }
+    }
+
+    /// compiling the module is slow so it is only done once it is needed
+    fn module(&self) -> Result<&Module> {
+        self.module.get_or_try_init(|| {
+            trace!("compiling {}", self.module_path.display());
+            Module::from_file(&ENGINE, &self.module_path)
+                .map_err(|err| eyre!("failed to load {}: {err:#}", self.module_path.display()))
+        })
+    }
+
+    /// calls the function exported as `script` and returns what it printed to stdout
+    ///
+    /// returns None if the module does not export `script`
+    fn call(&self, script: &str, caps: Capabilities) -> Result<Option<String>> {
+        let module = self.module()?;
+        if module.get_export(script).is_none() {
+            return Ok(None);
+        }
+        debug!("calling {script} in {}", self.module_path.display());
+        let stdout = MemoryOutputPipe::new(MAX_OUTPUT);
+        let stderr = MemoryOutputPipe::new(MAX_OUTPUT);
+        let mut wasi = WasiCtxBuilder::new();
+        wasi.args(&[vec![self.name.clone()], caps.args].concat())
+            .envs(&caps.env.iter().collect_vec())
+            .stdin(MemoryInputPipe::new(caps.stdin))
+            .stdout(stdout.clone())
+            .stderr(stderr.clone());
+        // guest paths are the same as the host paths so ASDF_INSTALL_PATH etc. work as-is
+        for dir in caps.read_dirs {
+            let guest_path = dir.to_string_lossy();
+            wasi.preopened_dir(dir, guest_path, DirPerms::READ, FilePerms::READ)
+                .map_err(|err| eyre!("failed to open {}: {err:#}", dir.display()))?;
+        }
+        for dir in caps.write_dirs {
+            let guest_path = dir.to_string_lossy();
+            wasi.preopened_dir(dir, guest_path, DirPerms::all(), FilePerms::all())
+                .map_err(|err| eyre!("failed to open {}: {err:#}", dir.display()))?;
+        }
+
+        let mut store: Store<WasiP1Ctx> = Store::new(&ENGINE, wasi.build_p1());
+        let mut linker = Linker::new(&ENGINE);
+        add_to_linker_sync(&mut linker, |wasi| wasi).map_err(|err| eyre!("{err:#}"))?;
+        let result = linker.instantiate(&mut store, module).and_then(|instance| {
+            // reactor modules need to be initialized before their exports are called
+            if let Ok(init) = instance.get_typed_func::<(), ()>(&mut store, "_initialize") {
+                init.call(&mut store, ())?;
+            }
+            let func = instance.get_typed_func::<(), ()>(&mut store, script)?;
+            func.call(&mut store, ())
+        });
+
+        let stderr = String::from_utf8_lossy(&stderr.contents())
+            .trim()
+            .to_string();
+        let code = match result {
+            Ok(()) => 0,
+            Err(err) => match err.downcast_ref::<I32Exit>() {
+                Some(I32Exit(code)) => *code,
+                None => return Err(eyre!("[{}] {script} failed: {err:#}", self.name)),
+            },
+        };
+        if code != 0 {
+            if !stderr.is_empty() {
+                eprintln!("{stderr}");
+            }
+            return Err(eyre!("[{}] {script} exited with code {code}", self.name));
+        }
+        if !stderr.is_empty() {
+            debug!("[{}] {script}: {stderr}", self.name);
+        }
+        Ok(Some(
+            String::from_utf8_lossy(&stdout.contents()).to_string(),
+        ))
+    }
+
+    /// the ASDF_* env vars for `rtv` and the tool's `install_env`
+    fn version_env(&self, rtv: &RuntimeVersion) -> IndexMap<String, String> {
+        rtv.install_env()
+            .into_iter()
+            .filter(|(k, _)| k.starts_with("ASDF_") || rtv.options.install_env.contains_key(k))
+            .collect()
+    }
+
+    fn download(&self, settings: &Settings, rtv: &RuntimeVersion, output: &str) -> Result<()> {
+        for line in output.lines().filter(|l| !l.trim().is_empty()) {
+            let mut parts = line.split_whitespace();
+            let url = parts.next().unwrap();
+            let filename = match parts.next() {
+                Some(filename) => filename,
+                None => url.rsplit('/').next().unwrap_or_default(),
+            };
+            if Path::new(filename).file_name() != Some(filename.as_ref()) {
+                return Err(eyre!(
+                    "[{}] invalid download filename: {filename}",
+                    self.name
+                ));
+            }
+            settings.ensure_online(&format!("download {url}"))?;
+            let path = rtv.download_path.join(filename);
+            debug!("downloading {url} to {}", path.display());
+            let mut file = File::create(&path)?;
+            reqwest::blocking::get(url)
+                .and_then(|resp| resp.error_for_status())
+                .and_then(|mut resp| resp.copy_to(&mut file))
+                .wrap_err_with(|| format!("failed to download {url}"))?;
+        }
+        Ok(())
+    }
+}
+
+impl Backend for WasmBackend {
+    fn list_remote_versions(&self, _settings: &Settings) -> Result<Vec<String>> {
+        let script = Script::ListAll.to_string();
+        let output = self
+            .call(&script, Capabilities::default())?
+            .ok_or_else(|| eyre!("{} does not export {script}", self.module_path.display()))?;
+        Ok(output.split_whitespace().map(|v| v.into()).collect())
+    }
+
+    fn latest_stable(&self, _settings: &Settings, query: &str) -> Result<Option<String>> {
+        let caps = Capabilities {
+            args: vec![query.into()],
+            ..Capabilities::default()
+        };
+        let output = self.call(&Script::LatestStable(query.into()).to_string(), caps)?;
+        Ok(output
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty()))
+    }
+
+    fn list_aliases(&self, _settings: &Settings) -> Result<Vec<(String, String)>> {
+        let output = self.call(&Script::ListAliases.to_string(), Capabilities::default())?;
+        Ok(parse_aliases(&output.unwrap_or_default()))
+    }
+
+    fn list_legacy_filenames(&self, _settings: &Settings) -> Result<Vec<String>> {
+        let script = Script::ListLegacyFilenames.to_string();
+        let output = self.call(&script, Capabilities::default())?;
+        Ok(output
+            .unwrap_or_default()
+            .split_whitespace()
+            .map(|v| v.into())
+            .collect())
+    }
+
+    /// the module gets the contents of the file on stdin rather than being able to read it
+    fn parse_legacy_file(&self, legacy_file: &Path, _settings: &Settings) -> Result<String> {
+        let body = fs::read_to_string(legacy_file)?;
+        let filename = legacy_file.to_string_lossy().to_string();
+        let script = Script::ParseLegacyFile(filename.clone()).to_string();
+        let caps = Capabilities {
+            args: vec![filename],
+            stdin: body.clone(),
+            ..Capabilities::default()
+        };
+        let legacy_version = self.call(&script, caps)?.unwrap_or(body);
+        Ok(legacy_version.trim().to_string())
+    }
+
+    fn install_version(
+        &self,
+        settings: &Settings,
+        rtv: &RuntimeVersion,
+        pr: &ProgressReport,
+    ) -> Result<()> {
+        let env = self.version_env(rtv);
+        let caps = Capabilities {
+            env: env.clone(),
+            ..Capabilities::default()
+        };
+        if let Some(output) = self.call(
+            &Script::Download(rtv.install_type.clone()).to_string(),
+            caps,
+        )? {
+            pr.set_message("downloading".into());
+            self.download(settings, rtv, &output)?;
+        }
+        pr.set_message("installing".into());
+        let script = Script::Install(rtv.install_type.clone()).to_string();
+        let caps = Capabilities {
+            env,
+            read_dirs: vec![&rtv.download_path],
+            write_dirs: vec![&rtv.install_path],
+            ..Capabilities::default()
+        };
+        self.call(&script, caps)?
+            .ok_or_else(|| eyre!("{} does not export {script}", self.module_path.display()))?;
+        Ok(())
+    }
+
+    fn uninstall_version(&self, rtv: &RuntimeVersion) -> Result<()> {
+        let caps = Capabilities {
+            env: self.version_env(rtv),
+            write_dirs: vec![&rtv.install_path],
+            ..Capabilities::default()
+        };
+        self.call(&Script::Uninstall.to_string(), caps)?;
+        Ok(())
+    }
+
+    fn list_bin_paths(&self, rtv: &RuntimeVersion) -> Result<Vec<String>> {
+        let caps = Capabilities {
+            env: self.version_env(rtv),
+            read_dirs: vec![&rtv.install_path],
+            ..Capabilities::default()
+        };
+        Ok(match self.call(&Script::ListBinPaths.to_string(), caps)? {
+            Some(output) => output.split_whitespace().map(|p| p.into()).collect(),
+            None => vec!["bin".into()],
+        })
+    }
+
+    fn exec_path(&self, rtv: &RuntimeVersion, bin_name: &str, path: &str) -> Result<String> {
+        let install_path = rtv.install_path.to_string_lossy().to_string();
+        let script = Script::ExecPath(install_path.clone(), bin_name.into(), path.into());
+        let caps = Capabilities {
+            args: vec![install_path, bin_name.into(), path.into()],
+            env: self.version_env(rtv),
+            read_dirs: vec![&rtv.install_path],
+            ..Capabilities::default()
+        };
+        Ok(match self.call(&script.to_string(), caps)? {
+            Some(output) => output.trim().into(),
+            None => path.into(),
+        })
+    }
+
+    /// unlike bin/exec-env this is not sourced, it prints `KEY=VALUE` lines
+    fn exec_env(&self, rtv: &RuntimeVersion) -> Result<HashMap<String, String>> {
+        let caps = Capabilities {
+            env: self.version_env(rtv),
+            read_dirs: vec![&rtv.install_path],
+            ..Capabilities::default()
+        };
+        let output = self.call("exec-env", caps)?.unwrap_or_default();
+        Ok(output
+            .lines()
+            .filter_map(|line| line.split_once('='))
+            .map(|(k, v)| (k.trim().to_string(), v.to_string()))
+            .collect())
+    }
+
+    fn help(&self) -> Result<Vec<(String, String)>> {
+        let scripts = [
+            ("overview", Script::HelpOverview),
+            ("deps", Script::HelpDeps),
+            ("config", Script::HelpConfig),
+            ("links", Script::HelpLinks),
+        ];
+        let mut help = vec![];
+        for (topic, script) in scripts {
+            if let Some(text) = self.call(&script.to_string(), Capabilities::default())? {
+                help.push((topic.into(), text.trim_end().into()));
+            }
+        }
+        Ok(help)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use pretty_assertions::assert_str_eq;
+
+    use crate::config::Config;
+    use crate::dirs;
+    use crate::plugins::{InstallType, Plugin};
+    use crate::toolset::ToolVersionOptions;
+
+    use super::*;
+
+    /// loads a module from test/fixtures/wasm, see the .wat files there for what it does
+    fn test_plugin(name: &str, dir: &Path, fixture: &str) -> Plugin {
+        let fixture = dirs::HOME.join(format!("fixtures/wasm/{fixture}.wasm"));
+        fs::copy(fixture, dir.join(MODULE_FILENAME)).unwrap();
+        let name = PluginName::from(name);
+        let plugin = Plugin::with_backend(&name, Arc::new(WasmBackend::new(&name, dir)), false);
+        plugin.clear_cache().unwrap();
+        plugin
+    }
+
+    #[test]
+    fn test_wasm_plugin() {
+        let config = Config::load().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let plugin = test_plugin("wasm-test", dir.path(), "plugin");
+        let versions = plugin.list_remote_versions(&config.settings).unwrap();
+        assert_str_eq!(versions.join(" "), "1.9.0 1.10.0");
+        let aliases = plugin.get_aliases(&config.settings).unwrap();
+        assert_eq!(aliases["lts"], "1.9");
+
+        let rtv = RuntimeVersion::new(
+            Arc::new(plugin),
+            InstallType::Version("1.10.0".into()),
+            ToolVersionOptions::default(),
+        );
+        rtv.install(&config, ProgressReport::new(true)).unwrap();
+        let installed = fs::read_to_string(rtv.install_path.join("installed")).unwrap();
+        assert_str_eq!(installed, "installed");
+        rtv.uninstall(&config.settings).unwrap();
+    }
+
+    #[test]
+    fn test_wasm_plugin_cannot_write_outside_install_path() {
+        let config = Config::load().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let plugin = test_plugin("wasm-sandbox-test", dir.path(), "sandbox");
+        let rtv = RuntimeVersion::new(
+            Arc::new(plugin),
+            InstallType::Version("1.10.0".into()),
+            ToolVersionOptions::default(),
+        );
+        let err = rtv.install(&config, ProgressReport::new(true)).unwrap_err();
+        assert_str_eq!(
+            err.to_string(),
+            "[wasm-sandbox-test] install exited with code 2"
+        );
+        assert!(!rtv.is_installed());
+    }
+}
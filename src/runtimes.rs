@@ -1,23 +1,30 @@
 use std::collections::HashMap;
+use std::env::join_paths;
 use std::fmt::{Display, Formatter};
 use std::fs::{create_dir_all, remove_dir_all, File};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::{Duration, SystemTime};
 use std::{fmt, fs};
 
 use color_eyre::eyre::{Result, WrapErr};
 use console::style;
+use duct::Expression;
+use filetime::{set_file_mtime, FileTime};
+use indexmap::IndexMap;
 use indicatif::ProgressStyle;
 use once_cell::sync::Lazy;
 
 use crate::cache::CacheManager;
 use crate::config::Config;
 use crate::config::Settings;
-use crate::env_diff::{EnvDiff, EnvDiffOperation};
 use crate::hash::hash_to_str;
-use crate::plugins::{InstallType, Plugin, Script, ScriptManager};
+use crate::plugins::{InstallType, Plugin};
+use crate::toolset::ToolVersionOptions;
 use crate::ui::progress_report::ProgressReport;
-use crate::{dirs, env, fake_asdf, file};
+use crate::{cmd, dirs, env, fake_asdf, file, hooks};
+
+const LAST_USED_RESOLUTION: Duration = Duration::from_secs(60 * 60);
 
 /// These represent individual plugin@version pairs of runtimes
 /// installed to ~/.local/share/rtx/runtimes
@@ -27,14 +34,18 @@ pub struct RuntimeVersion {
     pub plugin: Arc<Plugin>,
     pub install_path: PathBuf,
     pub install_type: InstallType,
-    download_path: PathBuf,
+    pub options: ToolVersionOptions,
+    pub download_path: PathBuf,
     cache_path: PathBuf,
-    script_man: ScriptManager,
     bin_paths_cache: CacheManager<Vec<String>>,
 }
 
 impl RuntimeVersion {
-    pub fn new(plugin: Arc<Plugin>, install_type: InstallType) -> Self {
+    pub fn new(
+        plugin: Arc<Plugin>,
+        install_type: InstallType,
+        options: ToolVersionOptions,
+    ) -> Self {
         let version = match &install_type {
             InstallType::Version(v) => v.to_string(),
             InstallType::Ref(r) => format!("ref-{r}"),
@@ -54,12 +65,6 @@ impl RuntimeVersion {
             _ => dirs::CACHE.join(&plugin.name).join(&version),
         };
         Self {
-            script_man: build_script_man(
-                install_type.clone(),
-                &plugin.plugin_path,
-                &install_path,
-                &download_path,
-            ),
             bin_paths_cache: CacheManager::new(cache_path.join("bin_paths.msgpack.zlib"))
                 .with_fresh_file(install_path.clone()),
             cache_path,
@@ -68,6 +73,7 @@ impl RuntimeVersion {
             version,
             plugin,
             install_type,
+            options,
         }
     }
 
@@ -87,34 +93,28 @@ impl RuntimeVersion {
         let settings = &config.settings;
         debug!("install {} {}", self, self.install_type);
 
+        self.run_hook(settings, "pre_asdf_install")?;
         self.create_install_dirs()?;
-        let download = Script::Download(self.install_type.clone());
-        let install = Script::Install(self.install_type.clone());
-
-        let run_script = |script| {
-            self.script_man.run_by_line(
-                script,
-                |output| {
-                    self.cleanup_install_dirs_on_error(settings);
-                    pr.finish_with_message(format!("error {}", style("✗").red().for_stderr()));
-                    if !settings.verbose && !output.trim().is_empty() {
-                        pr.println(output);
-                    }
-                },
-                |line| {
-                    if !line.trim().is_empty() {
-                        pr.set_message(line.into());
-                    }
-                },
-            )
+
+        let on_error = |output: String| {
+            self.cleanup_install_dirs_on_error(settings);
+            pr.finish_with_message(format!("error {}", style("✗").red().for_stderr()));
+            if !settings.verbose && !output.trim().is_empty() {
+                pr.println(output);
+            }
+        };
+        let run_cmd = |cmd| {
+            cmd::run_by_line(&self.plugin.name, cmd, on_error, |line| {
+                if !line.trim().is_empty() {
+                    pr.set_message(line.into());
+                }
+            })
         };
 
-        if self.script_man.script_exists(&download) {
-            pr.set_message("downloading".into());
-            run_script(download)?;
+        if let Err(err) = self.plugin.backend.install_version(settings, self, &pr) {
+            on_error(String::new());
+            return Err(err);
         }
-        pr.set_message("installing".into());
-        run_script(install)?;
         self.cleanup_install_dirs(settings);
 
         // attempt to touch all the .tool-version files to trigger updates in hook-env
@@ -129,29 +129,62 @@ impl RuntimeVersion {
         if let Err(err) = fs::remove_file(self.incomplete_file_path()) {
             debug!("error removing incomplete file: {:?}", err);
         }
+        let postinstall = match self.postinstall_cmds(settings) {
+            Ok(postinstall) => postinstall,
+            Err(err) => {
+                on_error(String::new());
+                return Err(err);
+            }
+        };
+        for (name, cmd) in postinstall {
+            pr.set_message(format!("running {name}"));
+            run_cmd(cmd)?;
+        }
+        self.run_hook(settings, "post_asdf_install")?;
         pr.finish_with_message(style("✓").green().for_stderr().to_string());
 
         Ok(())
     }
 
     pub fn list_bin_paths(&self) -> Result<Vec<PathBuf>> {
-        Ok(self
-            .bin_paths_cache
-            .get_or_try_init(|| self.fetch_bin_paths())?
+        let bin_paths = match (&self.options.bin_paths, &self.plugin.manifest.bin_paths) {
+            (Some(bin_paths), _) | (None, Some(bin_paths)) => bin_paths,
+            (None, None) => self
+                .bin_paths_cache
+                .get_or_try_init(|| self.plugin.backend.list_bin_paths(self))?,
+        };
+        Ok(bin_paths
             .iter()
             .map(|path| self.install_path.join(path))
             .collect())
     }
 
+    /// the executable to run for `bin_name`, found in the bin paths and then passed through the
+    /// plugin's bin/exec-path if it has one
     pub fn which(&self, bin_name: &str) -> Result<Option<PathBuf>> {
-        let bin_paths = self.list_bin_paths()?;
-        for bin_path in bin_paths {
-            let bin_path = bin_path.join(bin_name);
-            if bin_path.exists() {
-                return Ok(Some(bin_path));
-            }
+        let bin_path = match self.find_bin(bin_name)? {
+            Some(bin_path) => bin_path,
+            None => return Ok(None),
+        };
+        let path = match bin_path.strip_prefix(&self.install_path) {
+            Ok(path) => path.to_string_lossy().to_string(),
+            Err(_) => return Ok(Some(bin_path)),
+        };
+        let exec_path = self.plugin.backend.exec_path(self, bin_name, &path)?;
+        match exec_path.is_empty() {
+            true => Ok(Some(bin_path)),
+            false => Ok(Some(self.install_path.join(exec_path))),
         }
-        Ok(None)
+    }
+
+    /// the first of the bin paths that has `bin_name` in it, without running bin/exec-path
+    pub fn find_bin(&self, bin_name: &str) -> Result<Option<PathBuf>> {
+        let bin_path = self
+            .list_bin_paths()?
+            .into_iter()
+            .map(|bin_path| bin_path.join(bin_name))
+            .find(|bin_path| bin_path.exists());
+        Ok(bin_path)
     }
 
     pub fn is_installed(&self) -> bool {
@@ -164,13 +197,11 @@ impl RuntimeVersion {
         }
     }
 
-    pub fn uninstall(&self) -> Result<()> {
+    pub fn uninstall(&self, settings: &Settings) -> Result<()> {
         debug!("uninstall {} {}", self.plugin.name, self.version);
-        if self.plugin.plugin_path.join("bin/uninstall").exists() {
-            let err = self.script_man.run(Script::Uninstall);
-            if err.is_err() {
-                warn!("Failed to run uninstall script: {}", err.unwrap_err());
-            }
+        self.run_hook(settings, "pre_asdf_uninstall")?;
+        if let Err(err) = self.plugin.backend.uninstall_version(self) {
+            warn!("Failed to run uninstall script: {}", err);
         }
         let rmdir = |dir: &Path| {
             if !dir.exists() {
@@ -188,35 +219,134 @@ impl RuntimeVersion {
         if err.is_err() {
             warn!("Failed to remove download directory: {}", err.unwrap_err());
         }
+        if let Err(err) = rmdir(&self.cache_path) {
+            warn!("Failed to remove cache directory: {}", err);
+        }
+        self.run_hook(settings, "post_asdf_uninstall")?;
         Ok(())
     }
 
-    pub fn exec_env(&self) -> Result<HashMap<String, String>> {
-        let script = self.plugin.plugin_path.join("bin/exec-env");
-        if !self.is_installed() || !script.exists() {
-            return Ok(HashMap::new());
+    /// commands from the `postinstall` hooks, these run with this version's bin
+    /// paths and exec-env applied and get the packages in `default_packages_file` as arguments
+    fn postinstall_cmds(&self, settings: &Settings) -> Result<Vec<(String, Expression)>> {
+        let scripts = hooks::list(settings, &self.plugin.name, "postinstall")
+            .into_iter()
+            .map(|(hook, script)| (hook, script.clone()))
+            .collect::<Vec<_>>();
+        let packages = self.default_packages(settings)?;
+        if scripts.is_empty() {
+            if !packages.is_empty() {
+                warn!(
+                    "default_packages_file is set for {} but no postinstall hook is defined",
+                    self.plugin.name
+                );
+            }
+            return Ok(vec![]);
         }
-        let ed = EnvDiff::from_bash_script(&script, &self.script_man.env)?;
-        let env = ed
-            .to_patches()
+        let mut env = self.install_env();
+        env.extend(self.exec_env()?);
+        let path = join_paths([self.list_bin_paths()?, env::PATH.clone()].concat())?;
+        env.insert("PATH".into(), path.to_string_lossy().into());
+        let args = packages.iter().map(|p| p.as_str()).collect::<Vec<_>>();
+        Ok(scripts
             .into_iter()
-            .filter_map(|p| match p {
-                EnvDiffOperation::Add(key, value) => Some((key, value)),
-                EnvDiffOperation::Change(key, value) => Some((key, value)),
-                _ => None,
+            .map(|(name, script)| {
+                let mut cmd = hooks::cmd(&script, &args);
+                for (k, v) in env.iter() {
+                    cmd = cmd.env(k, v);
+                }
+                (name, cmd)
             })
-            .collect();
-        Ok(env)
+            .collect())
+    }
+
+    /// packages listed in the plugin's `default_packages_file`, one per line
+    fn default_packages(&self, settings: &Settings) -> Result<Vec<String>> {
+        let file = match settings.default_packages_file(&self.plugin.name) {
+            Some(file) if file.exists() => file,
+            _ => return Ok(vec![]),
+        };
+        let body = fs::read_to_string(&file)
+            .wrap_err_with(|| format!("failed to read {}", file.display()))?;
+        Ok(body
+            .lines()
+            .map(|line| line.split('#').next().unwrap_or_default().trim())
+            .filter(|line| !line.is_empty())
+            .map(String::from)
+            .collect())
     }
 
-    fn fetch_bin_paths(&self) -> Result<Vec<String>> {
-        let list_bin_paths = self.plugin.plugin_path.join("bin/list-bin-paths");
-        if list_bin_paths.exists() {
-            let output = self.script_man.cmd(Script::ListBinPaths).read()?;
-            Ok(output.split_whitespace().map(|e| e.into()).collect())
-        } else {
-            Ok(vec!["bin".into()])
+    fn run_hook(&self, settings: &Settings, hook: &str) -> Result<()> {
+        hooks::run(settings, &self.plugin.name, hook, &[&self.version])
+    }
+
+    /// records that this version was just put on PATH, see `last_used`
+    ///
+    /// this is called on every prompt so the timestamp is only written once an hour
+    pub fn touch_last_used(&self) -> Result<()> {
+        let path = self.last_used_path();
+        match self.last_used() {
+            Some(last_used) if last_used.elapsed().unwrap_or_default() < LAST_USED_RESOLUTION => {}
+            Some(_) => set_file_mtime(&path, FileTime::now())?,
+            None => {
+                create_dir_all(&self.cache_path)?;
+                File::create(&path)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// when this version was last used by hook-env, exec, or a shim
+    pub fn last_used(&self) -> Option<SystemTime> {
+        self.last_used_path().metadata().ok()?.modified().ok()
+    }
+
+    pub fn installed_at(&self) -> Option<SystemTime> {
+        self.install_path.metadata().ok()?.modified().ok()
+    }
+
+    fn last_used_path(&self) -> PathBuf {
+        self.cache_path.join("last_used")
+    }
+
+    /// bytes used by the install, download, and cache directories
+    pub fn disk_usage(&self) -> u64 {
+        [&self.install_path, &self.download_path, &self.cache_path]
+            .into_iter()
+            .filter(|dir| dir.exists())
+            .filter_map(|dir| file::disk_usage(dir).ok())
+            .sum()
+    }
+
+    pub fn exec_env(&self) -> Result<HashMap<String, String>> {
+        if !self.is_installed() {
+            return Ok(HashMap::new());
+        }
+        if let Some(env) = self.plugin.manifest.expand_exec_env(&self.install_env()) {
+            return Ok(env);
         }
+        self.plugin.backend.exec_env(self)
+    }
+
+    /// env vars for the commands that install or act on this version, these are the same ones
+    /// asdf passes to plugin scripts
+    pub fn install_env(&self) -> IndexMap<String, String> {
+        let mut env: IndexMap<String, String> = env::PRISTINE_ENV.clone().into_iter().collect();
+        env.insert("PATH".into(), fake_asdf::get_path_with_fake_asdf());
+        let install_path = self.install_path.to_string_lossy().to_string();
+        env.insert("ASDF_INSTALL_PATH".into(), install_path);
+        let download_path = self.download_path.to_string_lossy().to_string();
+        env.insert("ASDF_DOWNLOAD_PATH".into(), download_path);
+        env.insert("ASDF_CONCURRENCY".into(), num_cpus::get().to_string());
+        env.extend(self.options.install_env.clone());
+        let (install_type, version) = match &self.install_type {
+            InstallType::Version(v) => ("version", v),
+            InstallType::Ref(r) => ("ref", r),
+            _ => return env,
+        };
+        env.insert("ASDF_INSTALL_TYPE".into(), install_type.into());
+        env.insert("ASDF_INSTALL_VERSION".into(), version.into());
+        env
     }
 
     fn create_install_dirs(&self) -> Result<()> {
@@ -257,31 +387,47 @@ impl PartialEq for RuntimeVersion {
     }
 }
 
-fn build_script_man(
-    install_type: InstallType,
-    plugin_path: &Path,
-    install_path: &Path,
-    download_path: &Path,
-) -> ScriptManager {
-    let sm = ScriptManager::new(plugin_path.to_path_buf())
-        .with_envs(env::PRISTINE_ENV.clone())
-        .with_env("PATH".into(), fake_asdf::get_path_with_fake_asdf())
-        .with_env(
-            "ASDF_INSTALL_PATH".into(),
-            install_path.to_string_lossy().to_string(),
-        )
-        .with_env(
-            "ASDF_DOWNLOAD_PATH".into(),
-            download_path.to_string_lossy().to_string(),
-        )
-        .with_env("ASDF_CONCURRENCY".into(), num_cpus::get().to_string());
-    match install_type {
-        InstallType::Version(v) => sm
-            .with_env("ASDF_INSTALL_TYPE".into(), "version".into())
-            .with_env("ASDF_INSTALL_VERSION".into(), v),
-        InstallType::Ref(r) => sm
-            .with_env("ASDF_INSTALL_TYPE".into(), "ref".into())
-            .with_env("ASDF_INSTALL_VERSION".into(), r),
-        _ => sm,
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_str_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_postinstall() {
+        let mut config = Config::load().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let packages_file = dir.path().join("default-tiny-packages");
+        fs::write(&packages_file, "foo\n# comment\nbar # inline comment\n").unwrap();
+        config.settings.hooks.insert(
+            "postinstall_tiny".into(),
+            r#"echo "$JDXCODE_TINY $*" > "$ASDF_INSTALL_PATH/postinstall""#.into(),
+        );
+        config
+            .settings
+            .plugin_settings
+            .entry("tiny".into())
+            .or_default()
+            .default_packages_file = Some(packages_file);
+        let plugin = Arc::new(Plugin::new(&"tiny".into()));
+        let rtv = RuntimeVersion::new(
+            plugin,
+            InstallType::Version("1.0.0".into()),
+            ToolVersionOptions::default(),
+        );
+        rtv.install(&config, ProgressReport::new(true)).unwrap();
+        let output = fs::read_to_string(rtv.install_path.join("postinstall")).unwrap();
+        assert_str_eq!(output, "1.0.0 foo bar\n");
+
+        config
+            .settings
+            .hooks
+            .insert("postinstall_tiny".into(), "exit 1".into());
+        let err = rtv.install(&config, ProgressReport::new(true)).unwrap_err();
+        assert_str_eq!(
+            err.to_string(),
+            "[tiny] script exited with non-zero status: exit code 1"
+        );
+        assert!(!rtv.is_installed());
     }
 }
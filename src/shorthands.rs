@@ -30,6 +30,8 @@ pub fn get_shorthands(settings: &Settings) -> Shorthands {
             }
         }
     }
+    // plugins declared in `[plugins]` take precedence over shorthands
+    shorthands.extend(settings.plugins.clone());
     shorthands
 }
 
@@ -70,6 +72,21 @@ mod tests {
         assert_str_eq!(shorthands["xxxxxx"], "https://xxxxxx");
     }
 
+    #[test]
+    fn test_get_shorthands_with_plugins() {
+        let settings = Settings {
+            plugins: [
+                ("nodejs".into(), "https://nodejs-fork#v1".into()),
+                ("private".into(), "git@example.com:private.git".into()),
+            ]
+            .into(),
+            ..Settings::default()
+        };
+        let shorthands = get_shorthands(&settings);
+        assert_str_eq!(shorthands["nodejs"], "https://nodejs-fork#v1");
+        assert_str_eq!(shorthands["private"], "git@example.com:private.git");
+    }
+
     #[test]
     fn test_get_shorthands_missing_file() {
         let settings = Settings {
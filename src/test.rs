@@ -1,9 +1,10 @@
 use std::env::join_paths;
 use std::fs;
+use std::path::Path;
 
 use indoc::indoc;
 
-use crate::{assert_cli, env};
+use crate::{assert_cli, cmd, env};
 
 #[ctor::ctor]
 fn init() {
@@ -47,3 +48,26 @@ pub fn replace_path(input: &str) -> String {
         .replace(path.as_str(), "$PATH")
         .replace(home.as_str(), "~")
 }
+
+/// runs git in `dir` with a fixed author, for tests that build their own repositories
+pub fn run_git(dir: &Path, args: &[&str]) -> String {
+    let mut cmd_args = vec!["-C", dir.to_str().unwrap()];
+    cmd_args.extend(["-c", "user.name=rtx", "-c", "user.email=rtx@example.com"]);
+    cmd_args.extend(args);
+    cmd::cmd("git", cmd_args).read().unwrap()
+}
+
+/// uninstalls the plugins when dropped so tests that add plugins to the shared plugins dir
+/// don't leave them behind for other tests if they fail
+pub struct CleanupPlugins(pub Vec<&'static str>);
+
+impl Drop for CleanupPlugins {
+    fn drop(&mut self) {
+        for plugin in &self.0 {
+            let args = ["rtx", "plugins", "uninstall", plugin].map(String::from);
+            if let Err(err) = crate::cli::tests::cli_run(&args.to_vec()) {
+                eprintln!("failed to uninstall plugin {plugin}: {err:#}");
+            }
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use color_eyre::eyre::Result;
 use indexmap::IndexMap;
 use itertools::Itertools;
@@ -10,19 +12,23 @@ use crate::config::{config_file, Config};
 
 use crate::toolset::tool_version::ToolVersionType;
 use crate::toolset::{ToolSource, ToolVersion, Toolset};
-use crate::{env, file};
+use crate::{env, file, lockfile};
 
 #[derive(Debug)]
 pub struct ToolsetBuilder {
     args: Vec<RuntimeArg>,
+    config_files: Option<Vec<PathBuf>>,
     install_missing: bool,
+    resolve: bool,
 }
 
 impl ToolsetBuilder {
     pub fn new() -> Self {
         Self {
             args: Vec::new(),
+            config_files: None,
             install_missing: false,
+            resolve: true,
         }
     }
 
@@ -31,17 +37,34 @@ impl ToolsetBuilder {
         self
     }
 
+    /// loads versions from these config files instead of the ones found from the current directory
+    pub fn with_config_files(mut self, config_files: Vec<PathBuf>) -> Self {
+        self.config_files = Some(config_files);
+        self
+    }
+
     pub fn with_install_missing(mut self) -> Self {
         self.install_missing = true;
         self
     }
 
+    /// leaves the versions as they were requested instead of resolving them
+    /// so the remote versions are never fetched
+    pub fn without_resolve(mut self) -> Self {
+        self.resolve = false;
+        self
+    }
+
     pub fn build(self, config: &Config) -> Toolset {
         let mut toolset = Toolset::default().with_plugins(config.plugins.clone());
-        load_config_files(config, &mut toolset);
+        let config_files = self.config_files.as_ref().unwrap_or(&config.config_files);
+        load_config_files(config, config_files, &mut toolset);
+        lockfile::apply(&mut toolset);
         load_runtime_env(&mut toolset, env::vars().collect());
         load_runtime_args(&mut toolset, &self.args);
-        toolset.resolve(config);
+        if self.resolve {
+            toolset.resolve(config);
+        }
 
         if self.install_missing {
             if let Err(e) = toolset.install_missing(config) {
@@ -54,9 +77,8 @@ impl ToolsetBuilder {
     }
 }
 
-fn load_config_files(config: &Config, ts: &mut Toolset) {
-    let toolsets: Vec<_> = config
-        .config_files
+fn load_config_files(config: &Config, config_files: &[PathBuf], ts: &mut Toolset) {
+    let toolsets: Vec<_> = config_files
         .par_iter()
         .rev()
         .filter_map(|path| {
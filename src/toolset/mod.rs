@@ -17,6 +17,8 @@ pub use builder::ToolsetBuilder;
 pub use tool_source::ToolSource;
 pub use tool_version::ToolVersion;
 pub use tool_version::ToolVersionType;
+pub use tool_version_options::ToolVersionOptions;
+pub use version_range::VersionRange;
 
 use crate::cli::args::runtime::{RuntimeArg, RuntimeArgVersion};
 use crate::config::{Config, MissingRuntimeBehavior};
@@ -30,6 +32,8 @@ mod builder;
 mod tool_source;
 mod tool_version;
 mod tool_version_list;
+mod tool_version_options;
+mod version_range;
 
 /// a toolset is a collection of tools for various plugins
 ///
@@ -39,6 +43,8 @@ mod tool_version_list;
 #[derive(Debug, Default)]
 pub struct Toolset {
     pub versions: IndexMap<PluginName, ToolVersionList>,
+    /// env vars declared in config files (e.g.: `[env]` in .rtx.toml)
+    pub config_env: IndexMap<String, String>,
     source: Option<ToolSource>,
     plugins: IndexMap<PluginName, Arc<Plugin>>,
 }
@@ -68,6 +74,7 @@ impl Toolset {
             }
         }
         self.versions = other.versions; // swap to use other's first
+        self.config_env.extend(other.config_env); // other's env vars take precedence
         self.source = other.source;
     }
     pub fn resolve(&mut self, config: &Config) {
@@ -104,6 +111,9 @@ impl Toolset {
             MissingRuntimeBehavior::Warn => {
                 warn();
             }
+            MissingRuntimeBehavior::Prompt if config.settings.offline => {
+                warn();
+            }
             MissingRuntimeBehavior::Prompt => {
                 let versions = prompt_for_versions(&versions)?;
                 if versions.is_empty() {
@@ -113,6 +123,9 @@ impl Toolset {
                 }
             }
             MissingRuntimeBehavior::AutoInstall => {
+                config
+                    .settings
+                    .ensure_online(&format!("install {display_versions}"))?;
                 self.install_missing_versions(config, versions)?;
             }
         }
@@ -132,22 +145,22 @@ impl Toolset {
         config: &Config,
         selected_versions: Vec<ToolVersion>,
     ) -> Result<()> {
+        let plugins = selected_versions
+            .iter()
+            .map(|v| v.plugin_name.clone())
+            .unique()
+            .collect_vec();
         ThreadPoolBuilder::new()
             .num_threads(config.settings.jobs)
             .build()
             .unwrap()
             .install(|| -> Result<()> {
                 let mpr = MultiProgressReport::new(config.settings.verbose);
-                let plugins = selected_versions
-                    .iter()
-                    .map(|v| v.plugin_name.clone())
-                    .unique()
-                    .collect_vec();
                 let selected_versions = selected_versions
                     .into_iter()
                     .map(|v| v.r#type)
                     .collect::<HashSet<_>>();
-                self.install_missing_plugins(config, &mpr, plugins)?;
+                self.install_missing_plugins(config, &mpr, plugins.clone())?;
                 self.versions
                     .iter_mut()
                     .par_bridge()
@@ -172,7 +185,11 @@ impl Toolset {
                     })
                     .collect::<Result<Vec<()>>>()?;
                 Ok(())
-            })
+            })?;
+        for plugin in plugins.iter().filter_map(|p| self.plugins.get(p)) {
+            plugin.autoupdate(config);
+        }
+        Ok(())
     }
     fn install_missing_plugins(
         &mut self,
@@ -204,7 +221,12 @@ impl Toolset {
         let versions = self
             .versions
             .values()
-            .flat_map(|v| v.versions.iter().filter(|v| v.is_missing()).collect_vec())
+            .flat_map(|v| {
+                v.versions
+                    .iter()
+                    .filter(|v| v.is_missing() && !v.options.optional)
+                    .collect_vec()
+            })
             .cloned()
             .collect_vec();
         versions
@@ -217,9 +239,13 @@ impl Toolset {
             .into_par_iter()
             .map(|p| {
                 let versions = p.list_installed_versions()?;
-                Ok(versions
-                    .into_iter()
-                    .map(|v| RuntimeVersion::new(p.clone(), InstallType::Version(v))))
+                Ok(versions.into_iter().map(|v| {
+                    RuntimeVersion::new(
+                        p.clone(),
+                        InstallType::Version(v),
+                        ToolVersionOptions::default(),
+                    )
+                }))
             })
             .collect::<Result<Vec<_>>>()?
             .into_iter()
@@ -256,6 +282,15 @@ impl Toolset {
             .filter(|v| v.is_installed())
             .collect()
     }
+    /// records the last used time of the active runtimes, used by `rtx uninstall --unused-for`
+    pub fn touch_last_used(&self) {
+        for rtv in self.list_current_installed_versions() {
+            if let Err(err) = rtv.touch_last_used() {
+                debug!("failed to record last used time for {}: {:#}", rtv, err);
+            }
+        }
+    }
+
     pub fn env(&self) -> IndexMap<String, String> {
         let mut entries: IndexMap<String, String> = self
             .list_current_installed_versions()
@@ -271,6 +306,7 @@ impl Toolset {
             .into_iter()
             .rev()
             .collect();
+        entries.extend(self.config_env.clone());
         entries.sort_keys();
         entries
     }
@@ -362,7 +398,7 @@ impl Toolset {
         self.list_current_installed_versions()
             .into_par_iter()
             .find_first(|v| {
-                if let Ok(x) = v.which(bin_name) {
+                if let Ok(x) = v.find_bin(bin_name) {
                     x.is_some()
                 } else {
                     false
@@ -403,3 +439,25 @@ fn prompt_for_versions(versions: &[ToolVersion]) -> Result<Vec<ToolVersion>> {
         .map(|i| versions[i].clone())
         .collect())
 }
+
+#[cfg(test)]
+mod tests {
+    use indexmap::indexmap;
+
+    use super::*;
+
+    #[test]
+    fn test_merge_config_env() {
+        let mut ts = Toolset::new(ToolSource::Argument);
+        ts.config_env = indexmap! {
+            "FOO".into() => "parent".into(),
+            "BAR".into() => "parent".into(),
+        };
+        let mut child = Toolset::new(ToolSource::Argument);
+        child.config_env = indexmap! {"FOO".into() => "child".into()};
+        ts.merge(child);
+
+        assert_eq!(ts.config_env["FOO"], "child");
+        assert_eq!(ts.config_env["BAR"], "parent");
+    }
+}
@@ -7,7 +7,7 @@ use crate::file::display_path;
 #[derive(Debug, Clone)]
 pub enum ToolSource {
     ToolVersions(PathBuf),
-    // RtxRc(PathBuf),
+    RtxRc(PathBuf),
     LegacyVersionFile(PathBuf),
     Argument,
     Environment(String, String),
@@ -17,7 +17,7 @@ impl Display for ToolSource {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         match self {
             ToolSource::ToolVersions(path) => write!(f, "{}", display_path(path)),
-            // ToolSource::RtxRc(path) => write!(f, "{}", display_path(path)),
+            ToolSource::RtxRc(path) => write!(f, "{}", display_path(path)),
             ToolSource::LegacyVersionFile(path) => write!(f, "{}", display_path(path)),
             ToolSource::Argument => write!(f, "--runtime"),
             ToolSource::Environment(k, v) => write!(f, "{k}={v}"),
@@ -38,6 +38,9 @@ mod tests {
         let ts = ToolSource::ToolVersions(path);
         assert_str_eq!(ts.to_string(), "/home/user/.test-tool-versions");
 
+        let ts = ToolSource::RtxRc(PathBuf::from("/home/user/.rtx.toml"));
+        assert_str_eq!(ts.to_string(), "/home/user/.rtx.toml");
+
         let ts = ToolSource::Argument;
         assert_str_eq!(ts.to_string(), "--runtime");
 
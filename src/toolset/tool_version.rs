@@ -6,8 +6,10 @@ use color_eyre::eyre::Result;
 
 use crate::config::{Config, Settings};
 use crate::dirs;
+use crate::errors::Error::VersionNotFound;
 use crate::plugins::{InstallType, Plugin};
 use crate::runtimes::RuntimeVersion;
+use crate::toolset::{ToolVersionOptions, VersionRange};
 use crate::ui::progress_report::ProgressReport;
 
 /// represents a single version of a tool for a particular plugin
@@ -16,31 +18,69 @@ pub struct ToolVersion {
     pub plugin_name: String,
     pub r#type: ToolVersionType,
     pub rtv: Option<RuntimeVersion>,
+    pub options: ToolVersionOptions,
+    /// the exact version recorded in a lockfile, used instead of resolving
+    pub locked: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ToolVersionType {
     Version(String),
     Prefix(String),
+    Range(String),
     Ref(String),
     Path(String),
     System,
 }
 
+impl ToolVersionType {
+    /// parses a version as written in a config file
+    /// e.g.: "18", "prefix:18", ">=18.12 <20", "ref:master", "path:~/src/node", "system"
+    pub fn parse(version: &str) -> Self {
+        match version.split_once(':') {
+            Some(("prefix", v)) => ToolVersionType::Prefix(v.to_string()),
+            Some(("ref", v)) => ToolVersionType::Ref(v.to_string()),
+            Some(("path", v)) => ToolVersionType::Path(v.to_string()),
+            None if version == "system" => ToolVersionType::System,
+            None if VersionRange::is_range(version) => ToolVersionType::Range(version.to_string()),
+            _ => ToolVersionType::Version(version.to_string()),
+        }
+    }
+}
+
 impl ToolVersion {
     pub fn new(plugin_name: String, r#type: ToolVersionType) -> Self {
         Self {
             plugin_name,
             r#type,
             rtv: None,
+            options: ToolVersionOptions::default(),
+            locked: None,
         }
     }
 
+    pub fn with_options(mut self, options: ToolVersionOptions) -> Self {
+        self.options = options;
+        self
+    }
+
     pub fn resolve(&mut self, settings: &Settings, plugin: Arc<Plugin>) -> Result<()> {
+        if let Some(v) = &self.locked {
+            if self.rtv.is_none() {
+                let install_type = InstallType::Version(v.clone());
+                self.rtv = Some(RuntimeVersion::new(
+                    plugin,
+                    install_type,
+                    self.options.clone(),
+                ));
+            }
+            return Ok(());
+        }
         if self.rtv.is_none() {
             self.rtv = match &self.r#type {
                 ToolVersionType::Version(v) => self.resolve_version(settings, plugin, v)?,
                 ToolVersionType::Prefix(v) => self.resolve_prefix(settings, plugin, v)?,
+                ToolVersionType::Range(r) => self.resolve_range(settings, plugin, r)?,
                 ToolVersionType::Ref(r) => self.resolve_ref(plugin, r)?,
                 ToolVersionType::Path(path) => self.resolve_path(plugin, path)?,
                 ToolVersionType::System => None,
@@ -66,18 +106,24 @@ impl ToolVersion {
             Some(("prefix", p)) => {
                 return self.resolve_prefix(settings, plugin, p);
             }
+            _ if VersionRange::is_range(&v) => {
+                return self.resolve_range(settings, plugin, &v);
+            }
             _ => (),
         }
 
         if dirs::INSTALLS.join(&plugin.name).join(&v).exists() {
             // if the version is already installed, no need to fetch all of the remote versions
-            let rtv = RuntimeVersion::new(plugin, InstallType::Version(v));
+            let rtv = RuntimeVersion::new(plugin, InstallType::Version(v), self.options.clone());
+            return Ok(Some(rtv));
+        }
+        if let Some(rtv) = self.resolve_installed_prefix(settings, plugin.clone(), &v)? {
             return Ok(Some(rtv));
         }
 
         let matches = plugin.list_versions_matching(settings, &v)?;
         if matches.contains(&v) {
-            let rtv = RuntimeVersion::new(plugin, InstallType::Version(v));
+            let rtv = RuntimeVersion::new(plugin, InstallType::Version(v), self.options.clone());
             Ok(Some(rtv))
         } else {
             self.resolve_prefix(settings, plugin, &v)
@@ -90,27 +136,154 @@ impl ToolVersion {
         plugin: Arc<Plugin>,
         prefix: &str,
     ) -> Result<Option<RuntimeVersion>> {
+        if let Some(rtv) = self.resolve_installed_prefix(settings, plugin.clone(), prefix)? {
+            return Ok(Some(rtv));
+        }
+        if let Some(v) = plugin.latest_stable(settings, prefix)? {
+            let rtv = RuntimeVersion::new(plugin, InstallType::Version(v), self.options.clone());
+            return Ok(Some(rtv));
+        }
         let matches = plugin.list_versions_matching(settings, prefix)?;
         let v = match matches.last() {
             Some(v) => v,
             None => prefix,
             // None => Err(VersionNotFound(plugin.name.clone(), prefix.to_string()))?,
         };
-        let rtv = RuntimeVersion::new(plugin, InstallType::Version(v.to_string()));
+        let rtv = RuntimeVersion::new(
+            plugin,
+            InstallType::Version(v.to_string()),
+            self.options.clone(),
+        );
+        Ok(Some(rtv))
+    }
+
+    /// with the `prefer_installed` setting, resolves to the highest installed match
+    /// so the remote versions are only consulted if nothing installed matches
+    fn resolve_installed_prefix(
+        &self,
+        settings: &Settings,
+        plugin: Arc<Plugin>,
+        prefix: &str,
+    ) -> Result<Option<RuntimeVersion>> {
+        if !settings.prefer_installed {
+            return Ok(None);
+        }
+        let matches = plugin.list_installed_versions_matching(settings, prefix)?;
+        Ok(matches.last().map(|v| {
+            RuntimeVersion::new(
+                plugin,
+                InstallType::Version(v.to_string()),
+                self.options.clone(),
+            )
+        }))
+    }
+
+    fn resolve_range(
+        &self,
+        settings: &Settings,
+        plugin: Arc<Plugin>,
+        range: &str,
+    ) -> Result<Option<RuntimeVersion>> {
+        let range = VersionRange::parse(range)?;
+        let versions = match plugin.list_remote_versions(settings) {
+            Ok(versions) => versions.clone(),
+            Err(err) => {
+                warn!(
+                    "failed to list remote versions for {}: {}",
+                    plugin.name, err
+                );
+                plugin.list_installed_versions()?
+            }
+        };
+        let v = match range.max_satisfying(&versions) {
+            Some(v) => v,
+            None => Err(VersionNotFound(plugin.name.clone(), range.to_string()))?,
+        };
+        let rtv = RuntimeVersion::new(plugin, InstallType::Version(v), self.options.clone());
         Ok(Some(rtv))
     }
 
     fn resolve_ref(&self, plugin: Arc<Plugin>, r: &str) -> Result<Option<RuntimeVersion>> {
-        let rtv = RuntimeVersion::new(plugin, InstallType::Ref(r.to_string()));
+        let rtv = RuntimeVersion::new(
+            plugin,
+            InstallType::Ref(r.to_string()),
+            self.options.clone(),
+        );
         Ok(Some(rtv))
     }
 
     fn resolve_path(&self, plugin: Arc<Plugin>, path: &str) -> Result<Option<RuntimeVersion>> {
         let path = fs::canonicalize(path)?;
-        let rtv = RuntimeVersion::new(plugin, InstallType::Path(path));
+        let rtv = RuntimeVersion::new(plugin, InstallType::Path(path), self.options.clone());
         Ok(Some(rtv))
     }
 
+    /// the newest available version that satisfies what was requested
+    /// e.g.: `nodejs 18` -> the latest nodejs 18.x, regardless of what it currently resolves to
+    ///
+    /// returns None for versions that are not fetched from the plugin (refs, paths, system)
+    pub fn latest_version(
+        &self,
+        settings: &Settings,
+        plugin: Arc<Plugin>,
+    ) -> Result<Option<String>> {
+        let v = match &self.r#type {
+            ToolVersionType::Version(v)
+            | ToolVersionType::Prefix(v)
+            | ToolVersionType::Range(v) => resolve_alias(settings, plugin.clone(), v)?,
+            _ => return Ok(None),
+        };
+        let v = v.strip_prefix("prefix:").unwrap_or(&v);
+        if v.starts_with("ref:") || v.starts_with("path:") {
+            return Ok(None);
+        }
+        if VersionRange::is_range(v) {
+            let range = VersionRange::parse(v)?;
+            let include_prereleases = plugin.include_prereleases(settings);
+            let versions = plugin.list_remote_versions_filtered(settings, include_prereleases)?;
+            return Ok(range.max_satisfying(&versions));
+        }
+        plugin.latest_version(settings, v)
+    }
+
+    /// the installed versions this may be using, found without fetching the remote versions
+    /// e.g.: `nodejs 18` -> the highest installed nodejs 18.x, even if a newer one is available
+    ///
+    /// includes the version recorded in a lockfile as well as the installed match
+    pub fn installed_matches(
+        &self,
+        settings: &Settings,
+        plugin: Arc<Plugin>,
+    ) -> Result<Vec<String>> {
+        let installed = plugin.list_installed_versions()?;
+        let installed_prefix = |prefix: &str| -> Result<Option<String>> {
+            Ok(plugin
+                .list_installed_versions_matching(settings, prefix)?
+                .pop())
+        };
+        let installed_range = |range: &str| -> Result<Option<String>> {
+            Ok(VersionRange::parse(range)?.max_satisfying(&installed))
+        };
+        let v = match &self.r#type {
+            ToolVersionType::Version(v) => {
+                let v = resolve_alias(settings, plugin.clone(), v)?;
+                match v.split_once(':') {
+                    Some(("ref", r)) => Some(format!("ref-{r}")),
+                    Some(("path", _)) => None,
+                    Some(("prefix", p)) => installed_prefix(p)?,
+                    _ if VersionRange::is_range(&v) => installed_range(&v)?,
+                    _ if installed.contains(&v) => Some(v),
+                    _ => installed_prefix(&v)?,
+                }
+            }
+            ToolVersionType::Prefix(p) => installed_prefix(p)?,
+            ToolVersionType::Range(r) => installed_range(r)?,
+            ToolVersionType::Ref(r) => Some(format!("ref-{r}")),
+            ToolVersionType::Path(_) | ToolVersionType::System => None,
+        };
+        Ok(self.locked.iter().cloned().chain(v).collect())
+    }
+
     pub fn is_missing(&self) -> bool {
         match self.rtv {
             Some(ref rtv) => !rtv.is_installed(),
@@ -120,9 +293,10 @@ impl ToolVersion {
 
     pub fn install(&mut self, config: &Config, pr: ProgressReport) -> Result<()> {
         match self.r#type {
-            ToolVersionType::Version(_) | ToolVersionType::Prefix(_) | ToolVersionType::Ref(_) => {
-                self.rtv.as_ref().unwrap().install(config, pr)
-            }
+            ToolVersionType::Version(_)
+            | ToolVersionType::Prefix(_)
+            | ToolVersionType::Range(_)
+            | ToolVersionType::Ref(_) => self.rtv.as_ref().unwrap().install(config, pr),
             _ => Ok(()),
         }
     }
@@ -139,6 +313,7 @@ impl Display for ToolVersionType {
         match self {
             ToolVersionType::Version(v) => write!(f, "{v}"),
             ToolVersionType::Prefix(p) => write!(f, "prefix:{p}"),
+            ToolVersionType::Range(r) => write!(f, "{r}"),
             ToolVersionType::Ref(r) => write!(f, "ref:{r}"),
             ToolVersionType::Path(p) => write!(f, "path:{p}"),
             ToolVersionType::System => write!(f, "system"),
@@ -178,4 +353,16 @@ mod tests {
         let tv = ToolVersion::new(foo, ToolVersionType::System);
         assert_str_eq!(tv.to_string(), "foo@system");
     }
+
+    #[test]
+    fn test_tool_version_type_parse() {
+        let parse = ToolVersionType::parse;
+        assert_eq!(parse("18"), ToolVersionType::Version("18".into()));
+        assert_eq!(parse("prefix:18"), ToolVersionType::Prefix("18".into()));
+        assert_eq!(parse("^18"), ToolVersionType::Range("^18".into()));
+        assert_eq!(parse(">=18 <20"), ToolVersionType::Range(">=18 <20".into()));
+        assert_eq!(parse("ref:master"), ToolVersionType::Ref("master".into()));
+        assert_eq!(parse("path:."), ToolVersionType::Path(".".into()));
+        assert_eq!(parse("system"), ToolVersionType::System);
+    }
 }
@@ -0,0 +1,19 @@
+use indexmap::IndexMap;
+
+/// per-tool options that can be set in config files
+/// e.g.: `nodejs = { version = "18", install_env = { NODE_CONFIGURE_OPTS = "--debug" } }`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ToolVersionOptions {
+    /// extra env vars passed to the plugin scripts (download, install, etc.)
+    pub install_env: IndexMap<String, String>,
+    /// overrides the paths returned by bin/list-bin-paths, relative to the install path
+    pub bin_paths: Option<Vec<String>>,
+    /// if true, do not warn or prompt when this version is not installed
+    pub optional: bool,
+}
+
+impl ToolVersionOptions {
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+}
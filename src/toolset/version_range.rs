@@ -0,0 +1,129 @@
+use std::fmt::{Display, Formatter};
+
+use color_eyre::eyre::{eyre, Result};
+use itertools::Itertools;
+use semver::{Version, VersionReq};
+
+/// a semver range of versions, e.g.: `>=18.12 <20`, `~3.10.4`, `^1.2`
+///
+/// comparators may be separated by whitespace (like npm) or commas (like cargo)
+#[derive(Debug, Clone)]
+pub struct VersionRange {
+    raw: String,
+    req: VersionReq,
+}
+
+impl VersionRange {
+    /// returns true if the string looks like a range rather than a version or prefix
+    pub fn is_range(s: &str) -> bool {
+        s.starts_with(['>', '<', '=', '~', '^'])
+    }
+
+    pub fn parse(s: &str) -> Result<Self> {
+        let req = normalize(s)
+            .parse::<VersionReq>()
+            .map_err(|err| eyre!("invalid version range {s}: {err}"))?;
+        Ok(Self {
+            raw: s.to_string(),
+            req,
+        })
+    }
+
+    pub fn matches(&self, version: &str) -> bool {
+        match parse_version(version) {
+            Some(v) => self.req.matches(&v),
+            None => false,
+        }
+    }
+
+    /// returns the highest version that satisfies this range
+    pub fn max_satisfying<'a, I>(&self, versions: I) -> Option<String>
+    where
+        I: IntoIterator<Item = &'a String>,
+    {
+        versions
+            .into_iter()
+            .filter(|v| self.matches(v))
+            .max_by_key(|v| parse_version(v))
+            .map(|v| v.to_string())
+    }
+}
+
+impl Display for VersionRange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.raw)
+    }
+}
+
+/// converts npm-style ranges into the comma-separated syntax the semver crate expects
+/// e.g.: ">= 18.12 <20" -> ">=18.12, <20"
+fn normalize(s: &str) -> String {
+    let mut comparators: Vec<String> = vec![];
+    for part in s.replace(',', " ").split_whitespace() {
+        match comparators.last_mut() {
+            Some(prev) if prev.chars().all(|c| "<>=~^".contains(c)) => prev.push_str(part),
+            _ => comparators.push(part.to_string()),
+        }
+    }
+    comparators.into_iter().join(", ")
+}
+
+/// parses a version leniently, filling in missing minor/patch numbers
+/// e.g.: "v18" -> 18.0.0, "3.10" -> 3.10.0
+fn parse_version(v: &str) -> Option<Version> {
+    let v = v.trim().trim_start_matches('v');
+    let (core, rest) = v.split_at(v.find(['-', '+']).unwrap_or(v.len()));
+    let mut parts = core.split('.').collect_vec();
+    if parts.len() > 3 {
+        return None;
+    }
+    while parts.len() < 3 {
+        parts.push("0");
+    }
+    Version::parse(&format!("{}{rest}", parts.join("."))).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_range() {
+        assert!(VersionRange::is_range(">=18.12 <20"));
+        assert!(VersionRange::is_range("~3.10.4"));
+        assert!(VersionRange::is_range("^1.2"));
+        assert!(!VersionRange::is_range("18.12"));
+        assert!(!VersionRange::is_range("latest"));
+    }
+
+    #[test]
+    fn test_matches() {
+        let range = VersionRange::parse(">=18.12 <20").unwrap();
+        assert!(range.matches("18.12.0"));
+        assert!(range.matches("19.8.1"));
+        assert!(!range.matches("18.11.0"));
+        assert!(!range.matches("20.0.0"));
+        let range = VersionRange::parse("~3.10.4").unwrap();
+        assert!(range.matches("3.10.9"));
+        assert!(!range.matches("3.11.0"));
+        let range = VersionRange::parse("^1.2").unwrap();
+        assert!(range.matches("1.9"));
+        assert!(!range.matches("2.0.0"));
+        assert!(!range.matches("1.3.0-rc1"));
+    }
+
+    #[test]
+    fn test_max_satisfying() {
+        let versions = ["20.0.0", "18.9.0", "19.10.0", "19.9.0", "lts"].map(String::from);
+        let range = VersionRange::parse(">= 18.12, < 20").unwrap();
+        assert_eq!(range.max_satisfying(&versions), Some("19.10.0".into()));
+        let range = VersionRange::parse("^21").unwrap();
+        assert_eq!(range.max_satisfying(&versions), None);
+    }
+
+    #[test]
+    fn test_invalid_range() {
+        let err = VersionRange::parse(">=abc").unwrap_err();
+        assert!(err.to_string().starts_with("invalid version range >=abc"));
+    }
+}
@@ -0,0 +1,25 @@
+;; test plugin whose `install` writes `installed` into the install path (fd 4)
+;; regenerate plugin.wasm with `wat2wasm plugin.wat`
+(module
+  (import "wasi_snapshot_preview1" "fd_write"
+    (func $fd_write (param i32 i32 i32 i32) (result i32)))
+  (import "wasi_snapshot_preview1" "path_open"
+    (func $path_open (param i32 i32 i32 i32 i32 i64 i64 i32 i32) (result i32)))
+  (import "wasi_snapshot_preview1" "proc_exit" (func $proc_exit (param i32)))
+  (memory (export "memory") 1)
+  (data (i32.const 0) "1.10.0 1.9.0\n")
+  (data (i32.const 32) "installed")
+  (data (i32.const 64) "lts 1.9\n")
+  (func $print (param $fd i32) (param $ptr i32) (param $len i32)
+    (i32.store (i32.const 128) (local.get $ptr))
+    (i32.store (i32.const 132) (local.get $len))
+    (if (call $fd_write (local.get $fd) (i32.const 128) (i32.const 1) (i32.const 136))
+      (then (call $proc_exit (i32.const 1)))))
+  (func (export "list-all") (call $print (i32.const 1) (i32.const 0) (i32.const 13)))
+  (func (export "list-aliases") (call $print (i32.const 1) (i32.const 64) (i32.const 8)))
+  (func (export "install")
+    ;; O_CREAT|O_TRUNC with the fd_write right
+    (if (call $path_open (i32.const 4) (i32.const 0) (i32.const 32) (i32.const 9)
+          (i32.const 9) (i64.const 64) (i64.const 0) (i32.const 0) (i32.const 140))
+      (then (call $proc_exit (i32.const 2))))
+    (call $print (i32.load (i32.const 140)) (i32.const 32) (i32.const 9))))